@@ -37,30 +37,82 @@ enum Error {
     MissingArmType(String, String),
     #[error("Missing #[value = ...] attribute, expected for `{0}`-derived enum")]
     MissingValue(String),
-    #[error("Attemping to parse non-literal attribute for `value`: not yet supported")]
+    #[error("Unable to parse attribute for `value`: expected `#[value = <literal>]` or `#[value(<expr>)]`")]
     NonLiteralValue,
+    #[error("Range values are only supported for integer and `char` armtypes, found `{0}`")]
+    NonIntegerRange(String),
+    #[error("Invalid #[armtype(...)] attribute: {0}")]
+    InvalidArmType(String),
+    #[error("Unknown modifier `{0}` in #[armtype(...)] attribute")]
+    UnknownArmTypeModifier(String),
+    #[error("Invalid #[thisenum(...)] attribute: {0}")]
+    InvalidEnumOption(String),
+    #[error("Value of variant `{0}` does not match the inferred type `{1}`, consider adding #[armtype(...)] to the enum")]
+    MismatchedValueType(String, String),
+    #[error("Only one catch-all #[value(_)] variant is allowed, found `{0}` and `{1}`")]
+    MultipleCatchAll(String, String),
+    #[error("Catch-all variant `{0}` must have exactly one unnamed field, e.g. `{0}(T)`")]
+    InvalidCatchAll(String),
+    #[error("Catch-all variant `{0}` requires an owned armtype, found `{1}`")]
+    ReferenceCatchAll(String, String),
+    #[error("Invalid #[fill(...)] attribute on variant `{0}`: {1}")]
+    InvalidFill(String, String),
+    #[error("Missing field `{1}` in #[fill(...)] attribute on variant `{0}`, consider adding `Default`")]
+    MissingFillField(String, String),
+    #[error("Invalid `auto` in #[armtype(...)] attribute: {0}")]
+    InvalidAuto(String),
+    #[error("Invalid #[value_prefix = ...] attribute: expected a byte string or string literal")]
+    InvalidValuePrefix,
+    #[error("Value of variant `{0}` must be the same kind of literal as #[value_prefix = ...]")]
+    MismatchedValuePrefix(String),
+    #[error("Only one #[default] variant is allowed, found `{0}` and `{1}`")]
+    MultipleDefault(String, String),
+    #[error("#[default] variant `{0}` must be a unit variant")]
+    NonUnitDefault(String),
+    #[error("#[armtype(<type>, default)] requires a unit variant marked with #[default]")]
+    MissingDefault,
+    #[error("Value of variant `{0}` is {1}, which does not match the armtype `{2}`")]
+    MismatchedLitKind(String, String, String),
+    #[error("Value of variant `{0}` is {1} bytes long, which does not match the armtype `{2}`")]
+    MismatchedByteLength(String, usize, String),
+    #[error("Invalid `value` in #[armtype(...)] attribute: {0}")]
+    InvalidIdentValue(String),
+    #[error("Invalid `display` in #[armtype(...)] attribute: {0}")]
+    InvalidDisplay(String),
+    #[error("Invalid `from_str` in #[armtype(...)] attribute: {0}")]
+    InvalidFromStr(String),
+    #[error("Invalid `from` in #[armtype(...)] attribute: {0}")]
+    InvalidFrom(String),
+    #[error("Invalid #[rename = ...] attribute on variant `{0}`: expected a string literal")]
+    InvalidRename(String),
+    #[error("Computed value of variant `{0}` requires the standard library, and is not supported with the `no_std` feature")]
+    ComputedValueNoStd(String),
 }
 
-#[proc_macro_derive(Const, attributes(value, armtype))]
+#[proc_macro_derive(Const, attributes(value, armtype, const_value, const_type, thisenum, fill, skip, value_prefix, default, rename))]
 /// Add's constants to each arm of an enum
 /// 
 /// * To get the value as a reference, call the function [`<enum_name>::value`]
 /// * However, direct comparison to non-reference values are possible with
 ///   [`PartialEq`]
 /// 
-/// The `#[armtype = ...]` attribute is required for this macro to function, 
-/// and must be applied to **the enum**, since all values share the same type.
+/// The `#[armtype = ...]` attribute must be applied to **the enum**, since all
+/// values share the same type. If omitted, the type is inferred from the literal
+/// of the first `#[value = ...]`, and all other values must be the same kind of literal.
 /// 
 /// All values set will return a [`&'static T`] reference. To the input type,
 /// of [`T`] AND [`&T`]. If multiple references are used (e.g. `&&T`), then
 /// the return type will be [`&'static &T`].
 /// 
+/// Values, armtypes, modifiers (e.g. `#[armtype(u8, ord, hash)]`), enum options (e.g.
+/// `#[thisenum(unique)]`) and the other generated methods are described in the crate documentation.
+/// 
 /// # Example
 /// 
 /// ```
 /// use thisenum::Const;
 /// 
-/// #[derive(Const, Debug)]
+/// #[derive(Const)]
 /// #[armtype(i32)]
 /// enum MyEnum {
 ///     #[value = 0]
@@ -69,7 +121,7 @@ enum Error {
 ///     B,
 /// }
 /// 
-/// #[derive(Const, Debug)]
+/// #[derive(Const)]
 /// #[armtype(&[u8])]
 /// enum Tags {
 ///     #[value = b"\x00\x01\x7f"]
@@ -94,51 +146,338 @@ enum Error {
 ///     //
 ///     // to use this, use the `eq` feature in `Cargo.toml`: thisenum = { version = "x", features = ["eq"] }
 ///     #[cfg(feature = "eq")]
-///     assert_eq!(Tags::Length, b"\xba\x5e");
+///     assert_eq!(Tags::Length, b"\xba\x5e" as &[u8]);
 /// }
 /// ```
 pub fn thisenum_const(input: TokenStream) -> TokenStream {
     let name = "Const";
     let input = parse_macro_input!(input as DeriveInput);
+    let options = match get_enum_options(&input.attrs) {
+        Ok(options) => options,
+        Err(e) => panic!("{}", e),
+    };
+    let crate_path = &options.crate_path;
+    let std_path = std_path();
+    let alloc_path = alloc_path(crate_path);
+    let allow_lints = allow_lints();
     // --------------------------------------------------
     // extract the name, variants, and values
     // --------------------------------------------------
     let enum_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
     let variants = match input.data {
         Data::Enum(DataEnum { variants, .. }) => variants,
         _ => panic!("{}", Error::DeriveForNonEnum(name.into())),
     };
     // --------------------------------------------------
-    // extract the type
+    // phantom variants (e.g. `_Phantom(PhantomData<T>)`) only
+    // carry generic params, so they have no value and are
+    // never returned from `TryFrom`
+    // --------------------------------------------------
+    let (phantoms, mut variants): (syn::punctuated::Punctuated<_, syn::token::Comma>, syn::punctuated::Punctuated<_, syn::token::Comma>) = variants
+        .into_iter()
+        .partition(is_phantom_variant);
+    // --------------------------------------------------
+    // names used in name-based conversions, taken before
+    // the catch-all is removed from the variants
+    // --------------------------------------------------
+    let names = match variants
+        .iter()
+        .chain(phantoms.iter())
+        .map(|variant| get_name(variant, options.rename_all.as_deref()).map(|name| (variant.ident.clone(), name)))
+        .collect::<Result<Vec<_>, _>>() {
+        Ok(names) => names,
+        Err(e) => panic!("{}", e),
+    };
+    let name_of = |ident: &syn::Ident| names
+        .iter()
+        .find(|(name_ident, _)| name_ident == ident)
+        .map(|(_, name)| name.clone())
+        .unwrap_or_else(|| ident.to_string());
+    // --------------------------------------------------
+    // extract the armtype, if specified
+    // --------------------------------------------------
+    let armtype = match parse_armtype(&input.attrs) {
+        Ok(armtype) => armtype,
+        Err(e) => panic!("{}", e),
+    };
+    // --------------------------------------------------
+    // get unique assigned values. with `auto = increment`,
+    // missing values are assigned by incrementing the
+    // previous value, starting at 0
+    // --------------------------------------------------
+    let auto = match armtype.as_ref().and_then(|(_, _, options)| options.get("auto")) {
+        Some(auto) => match (get_auto_start(auto), &armtype) {
+            (Ok(start), Some((typ, false, _))) if is_integer_type(typ) => Some((start, typ)),
+            (Ok(_), _) => panic!("{}", Error::InvalidAuto("only supported for integer armtypes".into())),
+            (Err(e), _) => panic!("{}", e),
+        },
+        None => None,
+    };
+    // --------------------------------------------------
+    // with `value = ident_lower` (or `ident`, `ident_snake`),
+    // missing values are the transformed variant names
+    // --------------------------------------------------
+    let ident_value = match armtype.as_ref().and_then(|(_, _, options)| options.get("value")) {
+        Some(value) => match (get_ident_transform(value), &armtype) {
+            (Ok(transform), Some((typ, true, _))) if is_str_type(typ) => Some(transform),
+            (Ok(_), _) => panic!("{}", Error::InvalidIdentValue("only supported for `&str` armtypes".into())),
+            (Err(e), _) => panic!("{}", e),
+        },
+        None => None,
+    };
+    let mut values = match (auto, ident_value) {
+        (Some((start, typ)), _) => match get_auto_vals(name, &variants, start, typ) {
+            Ok(values) => Ok(values),
+            Err(e) => return TokenStream::from(e.to_compile_error()),
+        },
+        (None, Some(transform)) => get_ident_vals(name, &variants, transform),
+        (None, None) => variants
+            .iter()
+            .map(|variant| get_val(name.into(), &variant.attrs))
+            .collect::<Result<Vec<_>, _>>(),
+    }.unwrap();
+    // --------------------------------------------------
+    // integer values are converted to their bytes with
+    // `from = <int>_<endian>`, e.g. `from = u16_be`
+    // --------------------------------------------------
+    let from = match armtype.as_ref().and_then(|(_, _, options)| options.get("from")) {
+        Some(from) => match (get_from_int(from), &armtype) {
+            (Ok(from), Some((typ, true, _))) if is_byte_slice_type(typ) => Some(from),
+            (Ok(_), _) => panic!("{}", Error::InvalidFrom("only supported for `&[u8]` armtypes".into())),
+            (Err(e), _) => panic!("{}", e),
+        },
+        None => None,
+    };
+    if let Some((bits, signed, be)) = from {
+        values = match variants.iter().zip(values).map(|(variant, value)| int_to_bytes(variant, value, bits, signed, be)).collect() {
+            Ok(values) => values,
+            Err(e) => panic!("{}", e),
+        };
+    }
+    // --------------------------------------------------
+    // the catch-all variant (e.g. `#[value(_)] Other(u8)`)
+    // has no value of its own, so it is handled separately
+    // --------------------------------------------------
+    let catch_all = match get_catch_all(&variants, &values) {
+        Ok(catch_all) => catch_all,
+        Err(e) => panic!("{}", e),
+    };
+    if let Some((index, _)) = &catch_all {
+        variants = variants
+            .into_iter()
+            .enumerate()
+            .filter(|(i, _)| i != index)
+            .map(|(_, variant)| variant)
+            .collect();
+        values.remove(*index);
+    }
+    let catch_all = catch_all.map(|(_, ident)| ident);
+    // --------------------------------------------------
+    // an empty enum is uninhabited, so matching on `*self`
+    // needs no arms, whereas `&Self` is always inhabited
+    // --------------------------------------------------
+    let match_self = match variants.is_empty() && phantoms.is_empty() && catch_all.is_none() {
+        true => quote! { *self },
+        false => quote! { self },
+    };
+    // --------------------------------------------------
+    // prepend the enum-level prefix, e.g.
+    // `#[value_prefix = b"\xE0"]`, to each value
+    // --------------------------------------------------
+    let values = match get_value_prefix(&input.attrs) {
+        Ok(Some(prefix)) => match variants.iter().zip(values).map(|(variant, value)| apply_value_prefix(&prefix, variant, value)).collect() {
+            Ok(values) => values,
+            Err(e) => panic!("{}", e),
+        },
+        Ok(None) => values,
+        Err(e) => panic!("{}", e),
+    };
+    // --------------------------------------------------
+    // extract the type. if not specified, infer it from
+    // the literal of the first value
+    // --------------------------------------------------
+    let ((mut type_name, deref, armtype_options), mut type_name_raw) = match (armtype, get_type(&input.attrs)) {
+        (_, Err(e)) => panic!("{}", e),
+        (Some(armtype), Ok(Some(type_name_raw))) => (armtype, type_name_raw),
+        _ => match infer_shared_type(name, &variants, &values) {
+            Ok(type_name_raw) => {
+                let (type_name, deref) = split_deref_type(type_name_raw.clone());
+                ((type_name, deref, ArmTypeOptions::default()), type_name_raw)
+            },
+            Err(e) => panic!("{}", e),
+        },
+    };
+    // --------------------------------------------------
+    // values are `'static`, so nested references (e.g. the
+    // `&str` in `&[&str]`) are `'static` as well
+    // --------------------------------------------------
+    set_static_lifetimes(&mut type_name);
+    match type_name_raw {
+        Type::Reference(syn::TypeReference { ref mut elem, .. }) => set_static_lifetimes(elem),
+        ref mut typ => set_static_lifetimes(typ),
+    }
+    // --------------------------------------------------
+    // modifiers, e.g. `#[armtype(u8, no_inline)]`
+    // --------------------------------------------------
+    if let Some(modifier) = armtype_options.unknown(&["no_inline", "be", "auto", "from", "value", "display", "from_str", "return", "self_eq", "ord", "hash", "deref", "default"]) {
+        panic!("{}", Error::UnknownArmTypeModifier(modifier.to_string()));
+    }
+    if let (Some(catch_all), true) = (&catch_all, deref) {
+        panic!("{}", Error::ReferenceCatchAll(catch_all.to_string(), type_name_raw.to_token_stream().to_string()));
+    }
+    let inline = match armtype_options.has("no_inline") {
+        true => quote! { #[inline(never)] },
+        false => quote! { #[inline] },
+    };
+    // --------------------------------------------------
+    // `#[armtype(<type>, return = <R>)]` sets the return type
+    // of `value` to `&'static R`, coercing the value. since
+    // the coercion may use `Deref`, `value` is not `const`,
+    // and the generated code uses a hidden `const fn` instead
     // --------------------------------------------------
-    let (type_name, deref) = match get_deref_type(&input.attrs) {
-        Some((type_name, deref)) => (type_name, deref),
-        None => panic!("{}", Error::MissingArmType("applied to enum".into(), name.into())),
+    let return_type = match armtype_options.get("return").map(|expr| syn::parse2::<Type>(expr.to_token_stream())) {
+        Some(Ok(mut return_type)) => {
+            set_static_lifetimes(&mut return_type);
+            Some(return_type)
+        },
+        Some(Err(e)) => panic!("{}", Error::InvalidArmType(format!("expected a type for `return`, {}", e))),
+        None => None,
     };
-    let type_name_raw = match get_type(&input.attrs) {
-        Some(type_name_raw) => type_name_raw,
-        None => panic!("{}", Error::MissingArmType("applied to enum".into(), name.into())),
+    let (value_fn, value_attrs, return_value_impl) = match &return_type {
+        Some(return_type) => (
+            quote::format_ident!("__thisenum_value"),
+            quote! { #[doc(hidden)] },
+            quote! {
+                #inline
+                /// Returns the value of the enum variant
+                /// defined by [`Const`], as the type given by `return`
+                #[must_use]
+                pub fn value(&self) -> &'static #return_type {
+                    self.__thisenum_value()
+                }
+            },
+        ),
+        None => (
+            quote::format_ident!("value"),
+            quote! { #[must_use] },
+            quote! { },
+        ),
     };
     // --------------------------------------------------
-    // get unique assigned values
+    // literals must match the armtype, otherwise the error
+    // is reported on the generated code rather than the value
+    // --------------------------------------------------
+    let mismatched = variants
+        .iter()
+        .zip(values.iter())
+        .find_map(|(variant, value)| get_mismatched_lit_error(variant, &type_name_raw, value))
+        .map(|e| e.to_compile_error());
+    if let Some(mismatched) = mismatched {
+        return TokenStream::from(mismatched);
+    }
+    // --------------------------------------------------
+    // byte strings must have the length of byte array
+    // armtypes, e.g. `b"\x01\x02"` for `&[u8; 2]`
+    // --------------------------------------------------
+    let mismatched = variants
+        .iter()
+        .zip(values.iter())
+        .find_map(|(variant, value)| get_mismatched_byte_length_error(variant, &type_name_raw, value))
+        .map(|e| e.to_compile_error());
+    if let Some(mismatched) = mismatched {
+        return TokenStream::from(mismatched);
+    }
+    // --------------------------------------------------
+    // ranges (e.g. `#[value(200..=299)]`) are only valid
+    // as match patterns for integer and `char` types
     // --------------------------------------------------
-    let values = variants
+    if values.iter().any(|v| get_range_start(v).is_some()) && !is_integer_type(&type_name_raw) && get_primitive(&type_name_raw).as_deref() != Some("char") {
+        panic!("{}", Error::NonIntegerRange(type_name_raw.to_token_stream().to_string()));
+    }
+    // --------------------------------------------------
+    // skipped variants (e.g. `#[skip]`) keep their value,
+    // but are never returned from a value. give them a
+    // unique string, so that they are never repeated
+    // --------------------------------------------------
+    let skips = variants
+        .iter()
+        .map(|variant| variant.attrs.iter().any(|attr| attr.path.is_ident("skip")))
+        .collect::<Vec<_>>();
+    let values_string = values
         .iter()
-        .map(|variant| get_val(name.into(), &variant.attrs))
-        .collect::<Result<Vec<_>, _>>()
-        .unwrap();
-    let values_string = values.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+        .zip(skips.iter())
+        .enumerate()
+        .map(|(i, (v, skip))| match skip {
+            true => format!("#[skip] {}", i),
+            false => v.to_string(),
+        })
+        .collect::<Vec<_>>();
     let repeated_values_string = values_string.clone().into_iter().repeated();
     // --------------------------------------------------
+    // with `#[thisenum(unique)]`, repeated values fail to
+    // compile, by comparing each value to those before it
+    // in a `const` block
+    // --------------------------------------------------
+    let unique_check = match options.unique {
+        true if !input.generics.params.is_empty() => panic!("{}", Error::InvalidEnumOption("`unique` is not supported for generic enums".into())),
+        true => match get_unique_check(enum_name, &variants, &values, &skips, &type_name_raw) {
+            Ok(unique_check) => unique_check,
+            Err(e) => panic!("{}", e),
+        },
+        false => quote! { },
+    };
+    // --------------------------------------------------
+    // constants are only valid patterns if their type derives
+    // `PartialEq` and `Eq`, which can not be checked here. if
+    // any value is not a literal, compare values using `==`
+    // --------------------------------------------------
+    let all_patterns = values.iter().all(is_lit_value);
+    // --------------------------------------------------
+    // non-literal values (e.g. `#[value(compute(1))]`) are
+    // not valid patterns, and are not promoted to `&'static`
+    // references. store them in associated constants
+    // --------------------------------------------------
+    let value_consts = values
+        .iter()
+        .enumerate()
+        .filter(|(_, value)| !is_lit_value(value))
+        .map(|(i, value)| {
+            let ident = value_const_ident(i);
+            quote! { #[doc(hidden)] const #ident: #type_name_raw = #value; }
+        })
+        .collect::<Vec<_>>();
+    let values = values
+        .into_iter()
+        .enumerate()
+        .map(|(i, value)| match is_lit_value(&value) {
+            true => value,
+            false => {
+                let ident = value_const_ident(i);
+                quote! { Self::#ident }
+            },
+        })
+        .collect::<Vec<_>>();
+    // --------------------------------------------------
+    // variants with fields which can be constructed from
+    // `#[fill(...)]`, e.g. `Self::Variant { a: 1, b: 2 }`
+    // --------------------------------------------------
+    let fills = match variants.iter().map(|variant| get_fill(enum_name, variant)).collect::<Result<Vec<_>, _>>() {
+        Ok(fills) => fills,
+        Err(e) => panic!("{}", e),
+    };
+    // --------------------------------------------------
     // generate the output tokens
     // --------------------------------------------------
     let (
-        debug_arms,
-        variant_match_arms,
+        mut debug_arms,
+        mut variant_match_arms,
         mut variant_inv_match_arms
     ) = variants
         .iter()
-        .map(|variant| {
+        .zip(values.iter().zip(values_string.iter()))
+        .zip(fills.iter().zip(skips.iter()))
+        .map(|((variant, (value, value_string)), (fill, skip))| {
             let variant_name = &variant.ident;
             // ------------------------------------------------
             // number of args in the variant
@@ -151,15 +490,11 @@ pub fn thisenum_const(input: TokenStream) -> TokenStream {
                 syn::Fields::Unnamed(syn::FieldsUnnamed { ref unnamed, .. }) => unnamed.len(),
                 syn::Fields::Unit => 0,
             };
-            let value = match get_val(name.into(), &variant.attrs) {
-                Ok(value) => value,
-                Err(e) => panic!("{}", e),
-            };
             // ------------------------------------------------
             // check if the value is unique
             // this is used to prevent unreachable arms
             // ------------------------------------------------
-            let val_repeated = repeated_values_string.contains(&value.to_string());
+            let val_repeated = repeated_values_string.contains(value_string);
             // ------------------------------------------------
             // if the type input is a reference (e.g. &[u8] or &str)
             // then the return type will be 
@@ -174,9 +509,14 @@ pub fn thisenum_const(input: TokenStream) -> TokenStream {
             // as a result, need to ensure we are removing / adding
             // the `&` symbol wherever necessary
             // ------------------------------------------------
-            let args_tokens = match num_args {
-                0 => quote! {},
-                _ => {
+            // fields are matched using wildcards (`_` / `..`),
+            // which never bind, so fields are never moved out
+            // of `self`, even if they are not `Copy`
+            // ------------------------------------------------
+            let args_tokens = match variant.fields {
+                syn::Fields::Unit => quote! {},
+                syn::Fields::Named(_) => quote! { { .. } },
+                syn::Fields::Unnamed(_) => {
                     let args = (0..num_args).map(|_| quote! { _ });
                     quote! { ( #(#args),* ) }
                 },
@@ -184,28 +524,55 @@ pub fn thisenum_const(input: TokenStream) -> TokenStream {
             // ------------------------------------------------
             // debug arms implementation
             // ------------------------------------------------
-            let debug_arm = match get_val(name.into(), &variant.attrs) {
-                Ok(_) => quote! { #enum_name::#variant_name #args_tokens => write!(f, concat!(stringify!(#enum_name), "::", stringify!(#variant_name), ": {:?}"), self.value()), },
-                Err(e) => panic!("{}", e),
-            };
+            let debug_arm = quote! { #enum_name::#variant_name #args_tokens => write!(f, concat!(stringify!(#enum_name), "::", stringify!(#variant_name), ": {:?}"), self.#value_fn()), };
             // ------------------------------------------------
             // variant -> value
             // ------------------------------------------------
+            // for ranges, the value is the start of the range
+            // ------------------------------------------------
+            let start = get_range_start(value).unwrap_or_else(|| value.clone());
             let vma = match deref {
-                true => quote! { #enum_name::#variant_name #args_tokens => #value, },
-                false => quote! { #enum_name::#variant_name #args_tokens => &#value, },
+                true => quote! { #enum_name::#variant_name #args_tokens => #start, },
+                false => quote! { #enum_name::#variant_name #args_tokens => &#start, },
             };
             // ------------------------------------------------
             // value -> variant
             // ------------------------------------------------
-            match (num_args, val_repeated) {
-                (0, false) => (debug_arm, vma, Some(quote! { #value => Ok(#enum_name::#variant_name), })),
-                (_, _) => (debug_arm, vma, None),
+            match (num_args, val_repeated, fill) {
+                _ if *skip => (debug_arm, vma, None),
+                (0, false, _) => (debug_arm, vma, Some((value.clone(), quote! { Ok(#enum_name::#variant_name) }))),
+                (_, false, Some(fill)) => (debug_arm, vma, Some((value.clone(), quote! { Ok(#fill) }))),
+                (_, _, _) => (debug_arm, vma, None),
             }
         })
-        .into_iter()
         .unzip_n_vec();
     // --------------------------------------------------
+    // the catch-all variant returns its own value, which
+    // is borrowed from `self` rather than `'static`
+    // --------------------------------------------------
+    let (value_lifetime, value_doc) = match catch_all {
+        Some(ref catch_all) => {
+            debug_arms.push(quote! { #enum_name::#catch_all(_) => write!(f, concat!(stringify!(#enum_name), "::", stringify!(#catch_all), ": {:?}"), self.#value_fn()), });
+            variant_match_arms.push(quote! { #enum_name::#catch_all(value) => value, });
+            (quote! { }, quote! { concat!(" * [`&", stringify!(#type_name), "`], borrowed from `self` for the catch-all variant") })
+        },
+        None => (quote! { 'static }, quote! { concat!(" * [`&'static ", stringify!(#type_name), "`]") }),
+    };
+    phantoms.iter().for_each(|phantom| {
+        let phantom_name = &phantom.ident;
+        let args_tokens = match phantom.fields {
+            syn::Fields::Named(_) => quote! { { .. } },
+            _ => quote! { (..) },
+        };
+        debug_arms.push(quote! { #enum_name::#phantom_name #args_tokens => write!(f, concat!(stringify!(#enum_name), "::", stringify!(#phantom_name))), });
+        // ------------------------------------------------
+        // `value` is a `const fn`, which can only panic with
+        // a string literal, rather than `concat!`
+        // ------------------------------------------------
+        let message = format!("{}::{} has no value", enum_name, phantom_name);
+        variant_match_arms.push(quote! { #enum_name::#phantom_name #args_tokens => panic!(#message), });
+    });
+    // --------------------------------------------------
     // get the vima for repeated values
     // --------------------------------------------------
     let mut repeated_indices = values_string
@@ -221,184 +588,1159 @@ pub fn thisenum_const(input: TokenStream) -> TokenStream {
         .into_iter()
         .positions()
         .iter()
-        .map(|(_, pos)| match pos.len() {
-            ..=1 => quote! {},
-            _ => {
-                let val = values[pos[0]].clone();
-                quote! { #val => Err(::thisenum::Error::UnreachableValue(format!("{:?}", #val))), }
-            }
+        .filter(|(_, pos)| pos.len() > 1)
+        .map(|(_, pos)| {
+            let val = values[pos[0]].clone();
+            (val.clone(), quote! { Err(#crate_path::Error::UnreachableValue(#alloc_path::format!("{:?}", #val))) })
         })
         .collect::<Vec<_>>();
     // --------------------------------------------------
     // get all the indices of variants which have nested args
     // --------------------------------------------------
-    let arg_indices = variant_inv_match_arms
+    let arg_indices = variants
         .iter()
         .enumerate()
-        .filter(|(i, v)| v.is_none() && !repeated_indices.contains(&i))
+        .filter(|(i, variant)| !matches!(variant.fields, syn::Fields::Unit) && fills[*i].is_none() && !skips[*i] && !repeated_indices.contains(i))
         .map(|(i, _)| i)
         .collect::<Vec<_>>();
     let variant_inv_match_arms_args = values
         .clone()
         .into_iter()
-        .zip(variants)
+        .zip(variants.iter())
         .enumerate()
         .filter(|(i, _)| arg_indices.contains(i))
         .map(|(_, (value, variant))| {
             let variant_name = &variant.ident;
-            quote! { #value => Err(::thisenum::Error::UnableToReturnVariant(stringify!(#variant_name).into())), }
+            (value, quote! { Err(#crate_path::Error::UnableToReturnVariant(stringify!(#variant_name).into())) })
         })
         .collect::<Vec<_>>();
     // --------------------------------------------------
     // see deref comment above
     // --------------------------------------------------
     let variant_par_eq_lhs = match deref {
-        true => quote! { &self.value() == other },
-        false => quote! { self.value() == other },
+        true => quote! { &self.#value_fn() == other },
+        false => quote! { self.#value_fn() == other },
     };
     let variant_par_eq_rhs = match deref {
-        true => quote! { &other.value() == self },
-        false => quote! { other.value() == self },
+        true => quote! { &other.#value_fn() == self },
+        false => quote! { other.#value_fn() == self },
+    };
+    let (into_value_type, into_value_body) = match deref {
+        true => (quote! { &'static #type_name }, quote! { self.#value_fn() }),
+        false => (quote! { #type_name_raw }, quote! { *self.#value_fn() }),
     };
     let into_impl = match deref {
         false => quote! {
             #[automatically_derived]
+            #allow_lints
             #[doc = concat!(" [`Into`] implementation for [`", stringify!(#enum_name), "`]")]
-            impl ::std::convert::Into<#type_name_raw> for #enum_name {
-                #[inline]
+            impl #impl_generics #std_path::convert::Into<#type_name_raw> for #enum_name #ty_generics #where_clause {
+                #inline
                 fn into(self) -> #type_name_raw {
-                    *self.value()
+                    *self.#value_fn()
                 }
             }
         },
         true => quote! { },
     };
     // --------------------------------------------------
-    // return
+    // predicates for each variant, e.g. `is_key` for `Key`
     // --------------------------------------------------
-    let mut expanded = quote! {
-        #[automatically_derived]
-        impl #enum_name {
-            #[inline]
-            /// Returns the value of the enum variant
-            /// defined by [`Const`]
-            /// 
+    let predicates = variants
+        .iter()
+        .map(|variant| {
+            let args_tokens = match variant.fields {
+                syn::Fields::Unit => quote! { },
+                syn::Fields::Named(_) => quote! { { .. } },
+                syn::Fields::Unnamed(_) => quote! { (..) },
+            };
+            (&variant.ident, args_tokens)
+        })
+        .chain(catch_all.iter().map(|catch_all| (catch_all, quote! { (..) })))
+        .map(|(variant_name, args_tokens)| {
+            let predicate = quote::format_ident!("is_{}", to_snake_case(&variant_name.to_string()));
+            quote! {
+                #inline
+                #[doc = concat!(" Returns `true` if the enum variant is [`", stringify!(#enum_name), "::", stringify!(#variant_name), "`]")]
+                #[must_use]
+                pub const fn #predicate(&self) -> bool {
+                    matches!(self, #enum_name::#variant_name #args_tokens)
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+    // --------------------------------------------------
+    // `Display` writes either the name of the variant, or
+    // its value, e.g. `#[armtype(u8, display = name)]`
+    // --------------------------------------------------
+    let display_impl = match armtype_options.get("display") {
+        Some(display) => {
+            let body = match display {
+                _ if !cfg!(feature = "display") => panic!("{}", Error::InvalidDisplay("requires the `display` feature".into())),
+                syn::Expr::Path(syn::ExprPath { path, .. }) if path.is_ident("name") => {
+                    let display_arms = variants
+                        .iter()
+                        .map(|variant| &variant.ident)
+                        .chain(catch_all.iter())
+                        .chain(phantoms.iter().map(|phantom| &phantom.ident))
+                        .map(|variant_name| {
+                            let name = name_of(variant_name);
+                            quote! { #enum_name::#variant_name { .. } => #name, }
+                        });
+                    quote! { f.pad(match #match_self { #( #display_arms )* }) }
+                },
+                syn::Expr::Path(syn::ExprPath { path, .. }) if path.is_ident("value") => quote! {
+                    #std_path::fmt::Display::fmt(self.#value_fn(), f)
+                },
+                _ => panic!("{}", Error::InvalidDisplay("expected `name` or `value`".into())),
+            };
+            quote! {
+                #[automatically_derived]
+                #allow_lints
+                #[doc = concat!(" [`Display`](core::fmt::Display) implementation for [`", stringify!(#enum_name), "`]")]
+                impl #impl_generics #std_path::fmt::Display for #enum_name #ty_generics #where_clause {
+                    fn fmt(&self, f: &mut #std_path::fmt::Formatter<'_>) -> #std_path::fmt::Result {
+                        #body
+                    }
+                }
+            }
+        },
+        None => quote! { },
+    };
+    // --------------------------------------------------
+    // `FromStr` parses either the name of a unit variant,
+    // or for string and byte string armtypes its value,
+    // e.g. `#[armtype(&str, from_str = value)]`
+    // --------------------------------------------------
+    let from_str_impl = match armtype_options.get("from_str") {
+        Some(from_str) => {
+            let (doc, body) = match from_str {
+                _ if !cfg!(feature = "fromstr") => panic!("{}", Error::InvalidFromStr("requires the `fromstr` feature".into())),
+                syn::Expr::Path(syn::ExprPath { path, .. }) if path.is_ident("name") => {
+                    let (names, variant_names): (Vec<_>, Vec<_>) = variants
+                        .iter()
+                        .filter(|variant| matches!(variant.fields, syn::Fields::Unit))
+                        .map(|variant| (name_of(&variant.ident), &variant.ident))
+                        .unzip();
+                    (
+                        quote! { #[doc = " Parses the name of a unit variant"] },
+                        quote! {
+                            match s {
+                                #( #names => Ok(#enum_name::#variant_names), )*
+                                _ => Err(#crate_path::Error::InvalidValue(#alloc_path::format!("{:?}", s), stringify!(#enum_name).into())),
+                            }
+                        },
+                    )
+                },
+                syn::Expr::Path(syn::ExprPath { path, .. }) if path.is_ident("value") => {
+                    let value = match (deref, &type_name) {
+                        (true, typ) if is_str_type(typ) => quote! { s },
+                        (true, typ) if is_byte_slice_type(typ) => quote! { s.as_bytes() },
+                        _ => panic!("{}", Error::InvalidFromStr("`from_str = value` is only supported for `&str` and `&[u8]` armtypes".into())),
+                    };
+                    (
+                        quote! { #[doc = " Parses the value of a variant, the same as [`TryFrom<&str>`]"] },
+                        quote! { Self::from_value_ref(#value) },
+                    )
+                },
+                _ => panic!("{}", Error::InvalidFromStr("expected `name` or `value`".into())),
+            };
+            quote! {
+                #[automatically_derived]
+                #allow_lints
+                #[doc = concat!(" [`FromStr`](core::str::FromStr) implementation for [`", stringify!(#enum_name), "`]")]
+                ///
+                #doc
+                impl #impl_generics #std_path::str::FromStr for #enum_name #ty_generics #where_clause {
+                    type Err = #crate_path::Error;
+                    #inline
+                    fn from_str(s: &str) -> Result<Self, Self::Err> {
+                        #body
+                    }
+                }
+            }
+        },
+        None => quote! { },
+    };
+    // --------------------------------------------------
+    // owned values can be passed across FFI boundaries,
+    // e.g. for `#[repr(C)]` enums
+    // --------------------------------------------------
+    let ffi_impl = match cfg!(feature = "ffi") && !deref {
+        true => quote! {
+            #[automatically_derived]
+            #allow_lints
+            impl #impl_generics #enum_name #ty_generics #where_clause {
+                #inline
+                /// Returns a copy of the value of the enum variant, to be passed
+                /// across FFI boundaries. Same as `into_value`, without consuming `self`
+                ///
+                /// The value is a constant, so [`value`](Self::value) returns a `&'static`
+                /// reference to it, which is valid for the lifetime of the program
+                #[must_use]
+                pub fn to_ffi(&self) -> #type_name_raw {
+                    *self.#value_fn()
+                }
+            }
+        },
+        false => quote! { },
+    };
+    // --------------------------------------------------
+    // with the `deref` modifier, `*Enum::Variant` is the
+    // value. not to be confused with `deref`, which is
+    // whether the armtype is a reference
+    // --------------------------------------------------
+    let deref_trait_impl = match armtype_options.has("deref") {
+        true => quote! {
+            #[automatically_derived]
+            #allow_lints
+            #[doc = concat!(" [`Deref`](core::ops::Deref) implementation for [`", stringify!(#enum_name), "`], dereferencing to the value")]
+            impl #impl_generics #std_path::ops::Deref for #enum_name #ty_generics #where_clause {
+                type Target = #type_name;
+                #inline
+                fn deref(&self) -> &Self::Target {
+                    self.#value_fn()
+                }
+            }
+        },
+        false => quote! { },
+    };
+    // --------------------------------------------------
+    // byte slices can also be converted from `&str`,
+    // `Vec<u8>` and `&Vec<u8>`
+    // --------------------------------------------------
+    let try_from_str_impl = match deref && is_byte_slice_type(&type_name) {
+        true => quote! {
+            #[automatically_derived]
+            #allow_lints
+            #[doc = concat!(" [`TryFrom<&str>`] implementation for [`", stringify!(#enum_name), "`]")]
+            ///
+            /// Compares the bytes of the string against each constant
+            ///
             /// # Returns
-            /// 
-            #[doc = concat!(" * [`&'static ", stringify!(#type_name), "`]")]
-            pub fn value(&self) -> &'static #type_name {
-                match self {
-                    #( #variant_match_arms )*
+            ///
+            /// * [`Ok(T)`] where `T` is the enum variant
+            /// * [`Err(Error)`] if the conversion fails
+            impl #impl_generics #std_path::convert::TryFrom<&str> for #enum_name #ty_generics #where_clause {
+                type Error = #crate_path::Error;
+                #inline
+                fn try_from(value: &str) -> Result<Self, Self::Error> {
+                    <Self as #std_path::convert::TryFrom<#type_name_raw>>::try_from(value.as_bytes())
                 }
             }
-        }
-        #[automatically_derived]
-        #[cfg(feature = "eq")]
-        #[doc = concat!(" [`PartialEq<", stringify!(#type_name_raw) ,">`] implementation for [`", stringify!(#enum_name), "`]")]
-        ///
-        #[doc = concat!(" This is the LHS of the [`PartialEq`] implementation between [`", stringify!(#enum_name), "`] and [`", stringify!(#type_name_raw), "`]")]
-        /// 
-        /// # Returns
-        /// 
-        /// * [`true`] if the type and the enum are equal
-        /// * [`false`] if the type and the enum are not equal
-        impl ::std::cmp::PartialEq<#type_name_raw> for #enum_name {
-            #[inline]
-            fn eq(&self, other: &#type_name_raw) -> bool {
-                #variant_par_eq_lhs
+            #[automatically_derived]
+            #allow_lints
+            #[doc = concat!(" [`TryFrom<Vec<u8>>`] implementation for [`", stringify!(#enum_name), "`]")]
+            ///
+            /// Same as the [`TryFrom<&[u8]>`] implementation
+            ///
+            /// # Returns
+            ///
+            /// * [`Ok(T)`] where `T` is the enum variant
+            /// * [`Err(Error)`] if the conversion fails
+            impl #impl_generics #std_path::convert::TryFrom<#alloc_path::vec::Vec<u8>> for #enum_name #ty_generics #where_clause {
+                type Error = #crate_path::Error;
+                #inline
+                fn try_from(value: #alloc_path::vec::Vec<u8>) -> Result<Self, Self::Error> {
+                    Self::from_value_ref(value.as_slice())
+                }
             }
-        }
-        #[automatically_derived]
-        #[cfg(feature = "eq")]
-        #[doc = concat!(" [`PartialEq<", stringify!(#enum_name) ,">`] implementation for [`", stringify!(#type_name_raw), "`]")]
-        /// 
-        #[doc = concat!(" This is the RHS of the [`PartialEq`] implementation between [`", stringify!(#enum_name), "`] and [`", stringify!(#type_name_raw), "`]")]
-        /// 
-        /// # Returns
-        /// 
-        /// * [`true`] if the enum and the type are equal
-        /// * [`false`] if the enum and the type are not equal
-        impl ::std::cmp::PartialEq<#enum_name> for #type_name_raw {
-            #[inline]
-            fn eq(&self, other: &#enum_name) -> bool {
-                #variant_par_eq_rhs
+            #[automatically_derived]
+            #allow_lints
+            #[doc = concat!(" [`TryFrom<&Vec<u8>>`] implementation for [`", stringify!(#enum_name), "`]")]
+            ///
+            /// Same as the [`TryFrom<&[u8]>`] implementation
+            ///
+            /// # Returns
+            ///
+            /// * [`Ok(T)`] where `T` is the enum variant
+            /// * [`Err(Error)`] if the conversion fails
+            impl #impl_generics #std_path::convert::TryFrom<&#alloc_path::vec::Vec<u8>> for #enum_name #ty_generics #where_clause {
+                type Error = #crate_path::Error;
+                #inline
+                fn try_from(value: &#alloc_path::vec::Vec<u8>) -> Result<Self, Self::Error> {
+                    Self::from_value_ref(value.as_slice())
+                }
             }
-        }
-        #[automatically_derived]
-        #[doc = concat!(" [`Debug`] implementation for [`", stringify!(#enum_name), "`]")]
-        impl ::std::fmt::Debug for #enum_name {
-            fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
-                match self {
-                    #( #debug_arms )*
+        },
+        false => quote! { },
+    };
+    // --------------------------------------------------
+    // strings can also be converted from an owned `String`
+    // --------------------------------------------------
+    let try_from_string_impl = match deref && is_str_type(&type_name) {
+        true => quote! {
+            #[automatically_derived]
+            #allow_lints
+            #[doc = concat!(" [`TryFrom<String>`] implementation for [`", stringify!(#enum_name), "`]")]
+            ///
+            /// Same as the [`TryFrom<&str>`] implementation
+            ///
+            /// # Returns
+            ///
+            /// * [`Ok(T)`] where `T` is the enum variant
+            /// * [`Err(Error)`] if the conversion fails
+            impl #impl_generics #std_path::convert::TryFrom<#alloc_path::string::String> for #enum_name #ty_generics #where_clause {
+                type Error = #crate_path::Error;
+                #inline
+                fn try_from(value: #alloc_path::string::String) -> Result<Self, Self::Error> {
+                    <Self as #std_path::convert::TryFrom<#type_name_raw>>::try_from(value.as_str())
                 }
             }
-        }
-        #into_impl
+        },
+        false => quote! { },
     };
-    let variant_inv_match_arms = variant_inv_match_arms.into_iter().filter(|v| v.is_some()).map(|v| v.unwrap());
-    expanded = quote! {
-        #expanded
-        #[automatically_derived]
-        #[doc = concat!(" [`TryFrom`] implementation for [`", stringify!(#enum_name), "`]")]
-        ///
-        /// This is able to be derived since none of the Arms of the Enum had
-        /// any arguments. If that is the case, this implementation is 
-        /// non-existent.
-        /// 
-        /// # Returns
-        /// 
-        /// * [`Ok(T)`] where `T` is the enum variant
-        /// * [`Err(Error)`] if the conversion fails
-        impl ::std::convert::TryFrom<#type_name_raw> for #enum_name {
-            type Error = ::thisenum::Error;
-            #[inline]
-            fn try_from(value: #type_name_raw) -> Result<Self, Self::Error> {
-                match value {
-                    #( #variant_inv_match_arms )*
-                    #( #variant_inv_match_arms_repeated )*
-                    #( #variant_inv_match_arms_args )*
-                    _ => Err(::thisenum::Error::InvalidValue(format!("{:?}", value), stringify!(#enum_name).into())),
+    // --------------------------------------------------
+    // value-based comparisons, hashing, and borrowing
+    // --------------------------------------------------
+    // floats are compared / hashed by their bits, which
+    // is consistent with `Eq` / `Ord` / `Hash`
+    // --------------------------------------------------
+    let (value_eq, value_cmp, value_hash) = match is_float_type(&type_name_raw) {
+        true => (
+            quote! { self.#value_fn().to_bits() == other.#value_fn().to_bits() },
+            quote! { self.#value_fn().total_cmp(other.#value_fn()) },
+            quote! { #std_path::hash::Hash::hash(&self.#value_fn().to_bits(), state) },
+        ),
+        false => (
+            quote! { self.#value_fn() == other.#value_fn() },
+            quote! { #std_path::cmp::Ord::cmp(self.#value_fn(), other.#value_fn()) },
+            quote! { #std_path::hash::Hash::hash(self.#value_fn(), state) },
+        ),
+    };
+    let (ord, hash) = (armtype_options.has("ord"), armtype_options.has("hash"));
+    let self_eq_impl = match armtype_options.has("self_eq") || ord || hash {
+        true => quote! {
+            #[automatically_derived]
+            #allow_lints
+            #[doc = concat!(" [`PartialEq`] implementation for [`", stringify!(#enum_name), "`], comparing values")]
+            impl #impl_generics #std_path::cmp::PartialEq for #enum_name #ty_generics #where_clause {
+                #inline
+                fn eq(&self, other: &Self) -> bool {
+                    #value_eq
                 }
             }
-        }
+            #[automatically_derived]
+            #allow_lints
+            #[doc = concat!(" [`Eq`] implementation for [`", stringify!(#enum_name), "`], comparing values")]
+            impl #impl_generics #std_path::cmp::Eq for #enum_name #ty_generics #where_clause {}
+        },
+        false => quote! { },
     };
-    TokenStream::from(expanded)
-}
-
-#[proc_macro_derive(ConstEach, attributes(value, armtype))]
-/// Add's constants of any type to each arm of an enum
-/// 
-/// To get the value, the type must be explicitly passed
-/// as a generic to [`<enum_name>::value`]. This will automatically
-/// try to convert constant to the expected type using [`std::any::Any`] 
-/// and [`downcast_ref`]. Currently [`TryFrom`] is not supported, so typing
-/// is fairly strict. Upon failure, it will return [`None`].
-/// 
-/// * To get the value as a reference, call the function [`<enum_name>::value`]
-/// * Unlike [`Const`], this macro does not enable direct comparison
-///   using [`PartialEq`] when imported using the `eq` feature.
-/// 
-/// The `#[armtype = ...]` attribute is **NOT*** required for this macro to function, 
-/// but ***CAN** be applied to ***each individual arm*** of the enum, since values
-/// are not expected to share a type. If no type is given, then the type is
-/// inferred from the literal value in the `#[value = ...]` attribute.
-/// 
-/// All values set will return a [`Option<&'static T>`] reference. To the input type,
-/// of [`T`] AND [`&T`]. If multiple references are used (e.g. `&&T`), then
-/// the return type will be [`Option<&'static &T>`].
-/// 
-/// # Example
-/// 
-/// ```
-/// use thisenum::ConstEach;
-/// 
-/// #[derive(ConstEach, Debug)]
-/// enum MyEnum {
-///     #[armtype(u8)]
-///     #[value = 0xAA]
-///     A,
-///     #[value = "test3"]
-///     B,
+    let ord_impl = match ord {
+        true => quote! {
+            #[automatically_derived]
+            #allow_lints
+            #[doc = concat!(" [`PartialOrd`] implementation for [`", stringify!(#enum_name), "`], ordering by values")]
+            impl #impl_generics #std_path::cmp::PartialOrd for #enum_name #ty_generics #where_clause {
+                #inline
+                fn partial_cmp(&self, other: &Self) -> Option<#std_path::cmp::Ordering> {
+                    Some(#std_path::cmp::Ord::cmp(self, other))
+                }
+            }
+            #[automatically_derived]
+            #allow_lints
+            #[doc = concat!(" [`Ord`] implementation for [`", stringify!(#enum_name), "`], ordering by values")]
+            impl #impl_generics #std_path::cmp::Ord for #enum_name #ty_generics #where_clause {
+                #inline
+                fn cmp(&self, other: &Self) -> #std_path::cmp::Ordering {
+                    #value_cmp
+                }
+            }
+        },
+        false => quote! { },
+    };
+    let hash_impl = match hash {
+        true => quote! {
+            #[automatically_derived]
+            #allow_lints
+            #[doc = concat!(" [`Hash`] implementation for [`", stringify!(#enum_name), "`], hashing values")]
+            impl #impl_generics #std_path::hash::Hash for #enum_name #ty_generics #where_clause {
+                #inline
+                fn hash<H: #std_path::hash::Hasher>(&self, state: &mut H) {
+                    #value_hash
+                }
+            }
+        },
+        false => quote! { },
+    };
+    // --------------------------------------------------
+    // borrowing is only consistent with the value-based
+    // `Eq` / `Ord` / `Hash`, used as map keys
+    // --------------------------------------------------
+    let borrow_impl = match ord || hash {
+        true => quote! {
+            #[automatically_derived]
+            #allow_lints
+            #[doc = concat!(" [`Borrow<", stringify!(#type_name), ">`] implementation for [`", stringify!(#enum_name), "`]")]
+            ///
+            /// Allows looking up map keys by value
+            impl #impl_generics #std_path::borrow::Borrow<#type_name> for #enum_name #ty_generics #where_clause {
+                #inline
+                fn borrow(&self) -> &#type_name {
+                    self.#value_fn()
+                }
+            }
+        },
+        false => quote! { },
+    };
+    // --------------------------------------------------
+    // owned values are borrowed for `from_value_ref`
+    // --------------------------------------------------
+    let try_from_ref = match deref {
+        true => quote! { value },
+        false => quote! { &value },
+    };
+    // --------------------------------------------------
+    // the `#[default]` variant, if any. `Default` is only
+    // derived with `#[armtype(<type>, default)]`, since the
+    // attribute is shared with `#[derive(Default)]`
+    // --------------------------------------------------
+    let default = match get_default(&variants) {
+        Ok(default) => default,
+        Err(e) => panic!("{}", e),
+    };
+    let default_trait_impl = match (default, armtype_options.has("default")) {
+        (Some(default), true) => quote! {
+            #[automatically_derived]
+            #allow_lints
+            #[doc = concat!(" [`Default`] implementation for [`", stringify!(#enum_name), "`], returning [`", stringify!(#enum_name), "::", stringify!(#default), "`]")]
+            impl #impl_generics #std_path::default::Default for #enum_name #ty_generics #where_clause {
+                #inline
+                fn default() -> Self {
+                    #enum_name::#default
+                }
+            }
+        },
+        (None, true) => panic!("{}", Error::MissingDefault),
+        (_, false) => quote! { },
+    };
+    let default_impl = match default {
+        Some(default) => quote! {
+            #default_trait_impl
+            #[automatically_derived]
+            #allow_lints
+            impl #impl_generics #enum_name #ty_generics #where_clause {
+                #inline
+                #[doc = concat!(" Returns the enum variant with the given value, or [`", stringify!(#enum_name), "::", stringify!(#default), "`]")]
+                /// if the conversion fails
+                #[must_use]
+                pub fn from_value_or_default(value: #type_name_raw) -> Self {
+                    Self::from_value_ref(#try_from_ref).unwrap_or(#enum_name::#default)
+                }
+            }
+        },
+        None => quote! { },
+    };
+    // --------------------------------------------------
+    // unit-only enums can list all variants (and their
+    // values) in a constant
+    // --------------------------------------------------
+    let all_impl = match phantoms.is_empty() && catch_all.is_none() && variants.iter().all(|variant| matches!(variant.fields, syn::Fields::Unit)) {
+        true => {
+            // --------------------------------------------------
+            // skipped variants are not listed, and have no index
+            // --------------------------------------------------
+            let (all_variants, all_values): (Vec<_>, Vec<_>) = variants
+                .iter()
+                .zip(values.iter())
+                .zip(skips.iter())
+                .filter(|(_, skip)| !**skip)
+                .map(|((variant, value), _)| (&variant.ident, value))
+                .unzip();
+            let skipped_variants = variants
+                .iter()
+                .zip(skips.iter())
+                .filter(|(_, skip)| **skip)
+                .map(|(variant, _)| &variant.ident)
+                .collect::<Vec<_>>();
+            let skipped_messages = skipped_variants
+                .iter()
+                .map(|variant_name| format!("{}::{} is skipped, and has no index", enum_name, variant_name));
+            let len = all_variants.len();
+            let indices = (0..len).collect::<Vec<_>>();
+            let all_values = all_values.into_iter().map(|value| {
+                let start = get_range_start(value).unwrap_or_else(|| value.clone());
+                match deref {
+                    true => start,
+                    false => quote! { &#start },
+                }
+            });
+            // --------------------------------------------------
+            // numeric values can also be listed in ascending
+            // order, with floats ordered by `total_cmp`
+            // --------------------------------------------------
+            let sort = match (deref, &type_name_raw) {
+                (false, typ) if is_integer_type(typ) => Some(quote! { values.sort_unstable(); }),
+                (false, typ) if is_float_type(typ) => Some(quote! { values.sort_unstable_by(|a, b| a.total_cmp(b)); }),
+                _ => None,
+            };
+            let values_sorted = sort.map(|sort| quote! {
+                #inline
+                /// Returns the values of all variants of the enum, in ascending order
+                #[must_use]
+                pub fn values_sorted() -> #alloc_path::vec::Vec<#type_name_raw> {
+                    let mut values = Self::__THISENUM_VALUES_MAP
+                        .iter()
+                        .map(|(_, value)| **value)
+                        .collect::<#alloc_path::vec::Vec<_>>();
+                    #sort
+                    values
+                }
+            });
+            quote! {
+                #[automatically_derived]
+                #allow_lints
+                impl #impl_generics #enum_name #ty_generics #where_clause {
+                    /// All variants of the enum, other than skipped variants, in order of declaration
+                    pub const ALL: [Self; #len] = [ #( #enum_name::#all_variants ),* ];
+                    #[doc(hidden)]
+                    const __THISENUM_VALUES_MAP: &'static [(Self, &'static #type_name)] = &[ #( (#enum_name::#all_variants, #all_values) ),* ];
+                    #inline
+                    /// Returns all variants of the enum with their values, in order of declaration
+                    #[must_use]
+                    pub fn values_map() -> &'static [(Self, &'static #type_name)] {
+                        Self::__THISENUM_VALUES_MAP
+                    }
+                    #inline
+                    /// Returns the 0-based position of the variant in `ALL`
+                    ///
+                    /// # Panics
+                    ///
+                    /// If the variant is skipped, aka `#[skip]`
+                    #[must_use]
+                    pub const fn to_index(&self) -> usize {
+                        match #match_self {
+                            #( #enum_name::#all_variants => #indices, )*
+                            #( #enum_name::#skipped_variants => panic!(#skipped_messages), )*
+                        }
+                    }
+                    #inline
+                    /// Returns the variant at the given 0-based position in `ALL`,
+                    /// or [`None`] if the index is out of bounds
+                    #[must_use]
+                    pub const fn from_index(index: usize) -> Option<Self> {
+                        match index {
+                            #( #indices => Some(#enum_name::#all_variants), )*
+                            _ => None,
+                        }
+                    }
+                    #values_sorted
+                }
+            }
+        },
+        false => quote! { },
+    };
+    // --------------------------------------------------
+    // wider integers are converted to the armtype first,
+    // then delegated to `TryFrom<armtype>`
+    // --------------------------------------------------
+    let widen_impl = match cfg!(feature = "widen") {
+        true => {
+            let wider_types = get_wider_types(&type_name_raw);
+            let primitive = get_primitive(&type_name_raw).unwrap_or_default();
+            quote! {
+                #(
+                    #[automatically_derived]
+                    #allow_lints
+                    #[doc = concat!(" [`TryFrom<", stringify!(#wider_types), ">`] implementation for [`", stringify!(#enum_name), "`]")]
+                    ///
+                    #[doc = concat!(" Converts the value to [`", stringify!(#type_name_raw), "`] if it fits, then delegates to [`TryFrom<", stringify!(#type_name_raw), ">`]")]
+                    ///
+                    /// # Returns
+                    ///
+                    /// * [`Ok(T)`] where `T` is the enum variant
+                    /// * [`Err(Error)`] if the value does not fit, or the conversion fails
+                    impl #impl_generics #std_path::convert::TryFrom<#wider_types> for #enum_name #ty_generics #where_clause {
+                        type Error = #crate_path::Error;
+                        #inline
+                        fn try_from(value: #wider_types) -> Result<Self, Self::Error> {
+                            match <#type_name_raw as #std_path::convert::TryFrom<#wider_types>>::try_from(value) {
+                                Ok(value) => <Self as #std_path::convert::TryFrom<#type_name_raw>>::try_from(value),
+                                Err(_) => Err(#crate_path::Error::OutOfRange(#alloc_path::format!("{:?}", value), #primitive.into())),
+                            }
+                        }
+                    }
+                )*
+            }
+        },
+        false => quote! { },
+    };
+    // --------------------------------------------------
+    // unit variants with unique values, aka the variants
+    // which can be returned from a value
+    // --------------------------------------------------
+    let (unique_unit_names, unique_unit_values): (Vec<_>, Vec<_>) = variants
+        .iter()
+        .zip(values.iter().zip(values_string.iter()))
+        .zip(skips.iter())
+        .filter(|((variant, (_, value_string)), skip)| matches!(variant.fields, syn::Fields::Unit) && !**skip && !repeated_values_string.contains(value_string))
+        .map(|((variant, (value, _)), _)| (&variant.ident, value))
+        .unzip();
+    // --------------------------------------------------
+    // byte slices can be parsed from the front of a buffer
+    // --------------------------------------------------
+    let value_bytes_body = match (deref, armtype_options.has("be")) {
+        (true, _) if is_byte_slice_type(&type_name) => Some(quote! { #alloc_path::borrow::Cow::Borrowed(self.#value_fn()) }),
+        (true, _) if is_str_type(&type_name) => Some(quote! { #alloc_path::borrow::Cow::Borrowed(self.#value_fn().as_bytes()) }),
+        (false, false) if is_integer_type(&type_name) || is_float_type(&type_name) => Some(quote! { #alloc_path::borrow::Cow::Owned(self.#value_fn().to_le_bytes().to_vec()) }),
+        (false, true) if is_integer_type(&type_name) || is_float_type(&type_name) => Some(quote! { #alloc_path::borrow::Cow::Owned(self.#value_fn().to_be_bytes().to_vec()) }),
+        _ => None,
+    };
+    let value_bytes_impl = match value_bytes_body {
+        Some(body) => quote! {
+            #[automatically_derived]
+            #allow_lints
+            impl #impl_generics #enum_name #ty_generics #where_clause {
+                #inline
+                /// Returns the raw bytes of the value of the enum variant
+                ///
+                /// Numeric values are little-endian, unless the armtype
+                /// is marked with `be`, e.g. `#[armtype(u16, be)]`
+                ///
+                /// # Returns
+                ///
+                /// * [`Cow::Borrowed`] for `&[u8]` and `&str` armtypes
+                /// * [`Cow::Owned`] for numeric armtypes
+                #[must_use]
+                pub fn value_bytes(&self) -> #alloc_path::borrow::Cow<'static, [u8]> {
+                    #body
+                }
+            }
+        },
+        None => quote! { },
+    };
+    // --------------------------------------------------
+    // lists of aliases (e.g. `&[&str]`) can be converted
+    // from any of the aliases in the list
+    // --------------------------------------------------
+    let try_from_alias_impl = match deref && is_str_slice_type(&type_name) {
+        true => quote! {
+            #[automatically_derived]
+            #allow_lints
+            #[doc = concat!(" [`TryFrom<&str>`] implementation for [`", stringify!(#enum_name), "`]")]
+            ///
+            /// Returns the first variant whose list of aliases contains the string
+            ///
+            /// # Returns
+            ///
+            /// * [`Ok(T)`] where `T` is the enum variant
+            /// * [`Err(Error)`] if the conversion fails
+            impl #impl_generics #std_path::convert::TryFrom<&str> for #enum_name #ty_generics #where_clause {
+                type Error = #crate_path::Error;
+                #inline
+                fn try_from(value: &str) -> Result<Self, Self::Error> {
+                    #( if (#unique_unit_values).contains(&value) { return Ok(#enum_name::#unique_unit_names); } )*
+                    Err(#crate_path::Error::InvalidValue(#alloc_path::format!("{:?}", value), stringify!(#enum_name).into()))
+                }
+            }
+        },
+        false => quote! { },
+    };
+    let parse_all_impl = match deref && is_byte_slice_type(&type_name) {
+        true => quote! {
+            #[automatically_derived]
+            #allow_lints
+            impl #impl_generics #enum_name #ty_generics #where_clause {
+                #inline
+                /// Returns the enum variant with the given bytes. Same as [`TryFrom<&[u8]>`],
+                /// without requiring type annotations on byte string literals
+                ///
+                /// # Returns
+                ///
+                /// * [`Ok(T)`] where `T` is the enum variant
+                /// * [`Err(Error)`] if the conversion fails
+                pub fn from_bytes(bytes: &[u8]) -> Result<Self, #crate_path::Error> {
+                    Self::from_value_ref(bytes)
+                }
+                /// Greedily parses constants from the front of the input, until
+                /// no constant matches. If multiple constants match, the longest is used
+                ///
+                /// Only variants without nested arguments and with unique values are parsed
+                ///
+                /// # Returns
+                ///
+                /// * [`Vec<Self>`] of the parsed enum variants, in order
+                /// * [`&[u8]`] the remaining unparsed input
+                #[must_use]
+                pub fn parse_all(input: &[u8]) -> (#alloc_path::vec::Vec<Self>, &[u8]) {
+                    let mut input = input;
+                    let mut parsed = #alloc_path::vec::Vec::new();
+                    while let Some((variant, len)) = Self::__thisenum_prefix(input) {
+                        parsed.push(variant);
+                        input = &input[len..];
+                    }
+                    (parsed, input)
+                }
+                #inline
+                /// Returns the enum variant whose constant is a prefix of the input, and
+                /// the number of bytes consumed. If multiple constants match, the longest is used
+                ///
+                /// Only variants without nested arguments and with unique values are matched
+                ///
+                /// # Returns
+                ///
+                /// * [`Ok((T, usize))`] where `T` is the enum variant, and `usize` the length of its constant
+                /// * [`Err(Error)`] if no constant is a prefix of the input
+                pub fn try_from_prefix(input: &[u8]) -> Result<(Self, usize), #crate_path::Error> {
+                    match Self::__thisenum_prefix(input) {
+                        Some(matched) => Ok(matched),
+                        None => Err(#crate_path::Error::InvalidValue(#alloc_path::format!("{:?}", input), stringify!(#enum_name).into())),
+                    }
+                }
+                #[doc(hidden)]
+                fn __thisenum_prefix(input: &[u8]) -> Option<(Self, usize)> {
+                    let mut matched: Option<(Self, usize)> = None;
+                    #(
+                        let value: &[u8] = #unique_unit_values;
+                        if !value.is_empty() && input.starts_with(value) && matched.as_ref().map_or(true, |(_, len)| value.len() > *len) {
+                            matched = Some((#enum_name::#unique_unit_names, value.len()));
+                        }
+                    )*
+                    matched
+                }
+            }
+        },
+        false => quote! { },
+    };
+    // --------------------------------------------------
+    // arms with their own armtype (e.g. `#[armtype([u8; 2])]`)
+    // can be compared to that type with the `eq` feature
+    // --------------------------------------------------
+    let arm_types = match get_arm_types(&variants, &type_name_raw) {
+        Ok(arm_types) => arm_types,
+        Err(e) => panic!("{}", e),
+    };
+    // --------------------------------------------------
+    // strings can also be compared against an unsized
+    // `str`, e.g. in generic bounds
+    // --------------------------------------------------
+    let str_eq_impl = match deref && is_str_type(&type_name) {
+        true => quote! {
+            #[automatically_derived]
+            #allow_lints
+            #[cfg(feature = "eq")]
+            #[doc = concat!(" [`PartialEq<str>`] implementation for [`", stringify!(#enum_name), "`]")]
+            ///
+            /// Same as the [`PartialEq<&str>`] implementation, comparing against an unsized `str`
+            impl #impl_generics #std_path::cmp::PartialEq<str> for #enum_name #ty_generics #where_clause {
+                #inline
+                fn eq(&self, other: &str) -> bool {
+                    self.#value_fn() == other
+                }
+            }
+            #[automatically_derived]
+            #allow_lints
+            #[cfg(feature = "eq")]
+            #[doc = concat!(" [`PartialEq<", stringify!(#enum_name) ,">`] implementation for [`str`]")]
+            ///
+            /// Same as the [`PartialEq`] implementation for `&str`, comparing from an unsized `str`
+            impl #impl_generics #std_path::cmp::PartialEq<#enum_name #ty_generics> for str #where_clause {
+                #inline
+                fn eq(&self, other: &#enum_name #ty_generics) -> bool {
+                    other.#value_fn() == self
+                }
+            }
+        },
+        false => quote! { },
+    };
+    let arm_eq_impl = arm_types
+        .iter()
+        .map(|(arm_type, arm_names)| quote! {
+            #[automatically_derived]
+            #allow_lints
+            #[cfg(feature = "eq")]
+            #[doc = concat!(" [`PartialEq<", stringify!(#arm_type) ,">`] implementation for [`", stringify!(#enum_name), "`]")]
+            ///
+            /// Only the arms with this armtype are compared, all other arms are never equal
+            impl #impl_generics #std_path::cmp::PartialEq<#arm_type> for #enum_name #ty_generics #where_clause {
+                #inline
+                fn eq(&self, other: &#arm_type) -> bool {
+                    match self {
+                        #( #enum_name::#arm_names { .. } )|* => <#type_name as #std_path::cmp::PartialEq<#arm_type>>::eq(self.#value_fn(), other),
+                        #[allow(unreachable_patterns)]
+                        _ => false,
+                    }
+                }
+            }
+            #[automatically_derived]
+            #allow_lints
+            #[cfg(feature = "eq")]
+            #[doc = concat!(" [`PartialEq<", stringify!(#enum_name) ,">`] implementation for [`", stringify!(#arm_type), "`]")]
+            ///
+            /// Same as the [`PartialEq`] implementation above, with the enum on the RHS
+            impl #impl_generics #std_path::cmp::PartialEq<#enum_name #ty_generics> for #arm_type #where_clause {
+                #inline
+                fn eq(&self, other: &#enum_name #ty_generics) -> bool {
+                    <#enum_name #ty_generics as #std_path::cmp::PartialEq<#arm_type>>::eq(other, self)
+                }
+            }
+        })
+        .collect::<Vec<_>>();
+    // --------------------------------------------------
+    // return
+    // --------------------------------------------------
+    let mut expanded = quote! {
+        #unique_check
+        #[automatically_derived]
+        #allow_lints
+        impl #impl_generics #enum_name #ty_generics #where_clause {
+            #( #value_consts )*
+            #inline
+            /// Returns the value of the enum variant
+            /// defined by [`Const`]
+            /// 
+            /// # Returns
+            /// 
+            #[doc = #value_doc]
+            #value_attrs
+            pub const fn #value_fn(&self) -> &#value_lifetime #type_name {
+                match #match_self {
+                    #( #variant_match_arms )*
+                }
+            }
+            #return_value_impl
+            #inline
+            /// Checks if the value of the enum variant
+            /// is equal to the given value
+            /// 
+            /// # Returns
+            /// 
+            /// * [`true`] if the value of the variant and the given value are equal
+            /// * [`false`] otherwise
+            #[must_use]
+            pub fn matches_value(&self, v: &#type_name) -> bool {
+                self.#value_fn() == v
+            }
+            #inline
+            /// Consumes the enum variant, returning the value
+            /// defined by [`Const`]
+            /// 
+            /// # Returns
+            /// 
+            #[doc = concat!(" * [`", stringify!(#into_value_type), "`]")]
+            #[must_use]
+            pub fn into_value(self) -> #into_value_type {
+                #into_value_body
+            }
+        }
+        #[automatically_derived]
+        #allow_lints
+        #[cfg(feature = "eq")]
+        #[doc = concat!(" [`PartialEq<", stringify!(#type_name_raw) ,">`] implementation for [`", stringify!(#enum_name), "`]")]
+        ///
+        #[doc = concat!(" This is the LHS of the [`PartialEq`] implementation between [`", stringify!(#enum_name), "`] and [`", stringify!(#type_name_raw), "`]")]
+        /// 
+        /// # Returns
+        /// 
+        /// * [`true`] if the type and the enum are equal
+        /// * [`false`] if the type and the enum are not equal
+        impl #impl_generics #std_path::cmp::PartialEq<#type_name_raw> for #enum_name #ty_generics #where_clause {
+            #inline
+            fn eq(&self, other: &#type_name_raw) -> bool {
+                #variant_par_eq_lhs
+            }
+        }
+        #[automatically_derived]
+        #allow_lints
+        #[cfg(feature = "eq")]
+        #[doc = concat!(" [`PartialEq<", stringify!(#enum_name) ,">`] implementation for [`", stringify!(#type_name_raw), "`]")]
+        /// 
+        #[doc = concat!(" This is the RHS of the [`PartialEq`] implementation between [`", stringify!(#enum_name), "`] and [`", stringify!(#type_name_raw), "`]")]
+        /// 
+        /// # Returns
+        /// 
+        /// * [`true`] if the enum and the type are equal
+        /// * [`false`] if the enum and the type are not equal
+        impl #impl_generics #std_path::cmp::PartialEq<#enum_name #ty_generics> for #type_name_raw #where_clause {
+            #inline
+            fn eq(&self, other: &#enum_name #ty_generics) -> bool {
+                #variant_par_eq_rhs
+            }
+        }
+        #[automatically_derived]
+        #allow_lints
+        #[cfg(feature = "eq")]
+        #[doc = concat!(" [`PartialEq<&", stringify!(#type_name_raw) ,">`] implementation for [`", stringify!(#enum_name), "`]")]
+        ///
+        /// Same as the [`PartialEq`] implementation above, comparing against a reference
+        impl #impl_generics #std_path::cmp::PartialEq<&#type_name_raw> for #enum_name #ty_generics #where_clause {
+            #inline
+            fn eq(&self, other: &&#type_name_raw) -> bool {
+                <Self as #std_path::cmp::PartialEq<#type_name_raw>>::eq(self, *other)
+            }
+        }
+        #[automatically_derived]
+        #allow_lints
+        #[cfg(feature = "eq")]
+        #[doc = concat!(" [`PartialEq<", stringify!(#enum_name) ,">`] implementation for [`&", stringify!(#type_name_raw), "`]")]
+        ///
+        /// Same as the [`PartialEq`] implementation above, comparing from a reference
+        impl #impl_generics #std_path::cmp::PartialEq<#enum_name #ty_generics> for &#type_name_raw #where_clause {
+            #inline
+            fn eq(&self, other: &#enum_name #ty_generics) -> bool {
+                <#type_name_raw as #std_path::cmp::PartialEq<#enum_name #ty_generics>>::eq(*self, other)
+            }
+        }
+        #str_eq_impl
+        #( #arm_eq_impl )*
+        #[automatically_derived]
+        #allow_lints
+        #[doc = concat!(" [`Debug`] implementation for [`", stringify!(#enum_name), "`]")]
+        impl #impl_generics #std_path::fmt::Debug for #enum_name #ty_generics #where_clause {
+            fn fmt(&self, f: &mut #std_path::fmt::Formatter<'_>) -> #std_path::fmt::Result {
+                match #match_self {
+                    #( #debug_arms )*
+                }
+            }
+        }
+        #[automatically_derived]
+        #allow_lints
+        #[doc = concat!(" [`AsRef<", stringify!(#type_name), ">`] implementation for [`", stringify!(#enum_name), "`]")]
+        impl #impl_generics #std_path::convert::AsRef<#type_name> for #enum_name #ty_generics #where_clause {
+            #inline
+            fn as_ref(&self) -> &#type_name {
+                self.#value_fn()
+            }
+        }
+        #into_impl
+    };
+    // --------------------------------------------------
+    // value -> variant, as (value, result) pairs
+    // --------------------------------------------------
+    let (inv_values, inv_results): (Vec<_>, Vec<_>) = variant_inv_match_arms
+        .into_iter()
+        .flatten()
+        .chain(variant_inv_match_arms_repeated)
+        .chain(variant_inv_match_arms_args)
+        .unzip();
+    // --------------------------------------------------
+    // the value is compared by reference, so that it is
+    // never moved (e.g. non-`Copy` armtypes)
+    // --------------------------------------------------
+    let value_ref = match deref {
+        true => quote! { value },
+        false => quote! { *value },
+    };
+    // --------------------------------------------------
+    // with `#[thisenum(verbose_errors)]`, the error lists
+    // the values of all variants that can be converted to,
+    // aka not skipped, repeated or unfilled variants
+    // --------------------------------------------------
+    let candidates = values_string
+        .iter()
+        .enumerate()
+        .filter(|(i, value_string)| {
+            !skips[*i]
+                && !repeated_values_string.contains(*value_string)
+                && (matches!(variants[*i].fields, syn::Fields::Unit) || fills[*i].is_some())
+        })
+        .map(|(_, value_string)| value_string)
+        .collect::<Vec<_>>();
+    let invalid_value = match (catch_all, options.verbose_errors) {
+        (Some(ref catch_all), _) => quote! { Ok(#enum_name::#catch_all(#std_path::clone::Clone::clone(value))) },
+        (None, true) => quote! {
+            Err(#crate_path::Error::InvalidValueWithCandidates(
+                #alloc_path::format!("{:?}", value),
+                stringify!(#enum_name).into(),
+                #alloc_path::vec![ #( #candidates.into() ),* ],
+            ))
+        },
+        (None, false) => quote! { Err(#crate_path::Error::InvalidValue(#alloc_path::format!("{:?}", value), stringify!(#enum_name).into())) },
+    };
+    let byte_table = match deref && is_byte_slice_type(&type_name) {
+        true => get_byte_table(&inv_values),
+        false => None,
+    };
+    let try_from_body = match (is_float_type(&type_name_raw), byte_table, all_patterns) {
+        // --------------------------------------------------
+        // floats are compared by their bits, since `==` is
+        // not exact (e.g. NaN) and floats are not valid patterns
+        // --------------------------------------------------
+        (true, _, _) => quote! {
+            #( if #type_name_raw::to_bits(#value_ref) == #type_name_raw::to_bits(#inv_values) { return #inv_results; } )*
+            #invalid_value
+        },
+        // --------------------------------------------------
+        // byte strings are sorted at compile time, and
+        // searched using a binary search
+        // --------------------------------------------------
+        (false, Some((table_values, table_indices)), _) => {
+            let num_entries = table_values.len();
+            let result_indices = 0..inv_results.len();
+            quote! {
+                static TABLE: [(&[u8], usize); #num_entries] = [ #( (#table_values, #table_indices), )* ];
+                match TABLE.binary_search_by(|(key, _)| (*key).cmp(value)) {
+                    Ok(i) => match TABLE[i].1 {
+                        #( #result_indices => #inv_results, )*
+                        _ => unreachable!(),
+                    },
+                    Err(_) => #invalid_value,
+                }
+            }
+        },
+        (false, None, true) => quote! {
+            match #value_ref {
+                #( #inv_values => #inv_results, )*
+                _ => #invalid_value,
+            }
+        },
+        // --------------------------------------------------
+        // non-literal values are compared using `==`, while
+        // literals (including ranges) are still matched
+        // --------------------------------------------------
+        (false, None, false) => {
+            let conditions = inv_values
+                .iter()
+                .map(|v| match is_lit_value(v) {
+                    true => quote! { matches!(#value_ref, #v) },
+                    false => quote! { #value_ref == #v },
+                });
+            quote! {
+                #( if #conditions { return #inv_results; } )*
+                #invalid_value
+            }
+        },
+    };
+    // --------------------------------------------------
+    // every `TryFrom` implementation, unless suppressed with
+    // `#[thisenum(derive_try_from = false)]`
+    // --------------------------------------------------
+    let try_from_impl = match options.derive_try_from {
+        true => quote! {
+            #[automatically_derived]
+            #allow_lints
+            #[doc = concat!(" [`TryFrom`] implementation for [`", stringify!(#enum_name), "`]")]
+            ///
+            /// This is able to be derived since none of the Arms of the Enum had
+            /// any arguments. If that is the case, this implementation is 
+            /// non-existent.
+            /// 
+            /// # Returns
+            /// 
+            /// * [`Ok(T)`] where `T` is the enum variant
+            /// * [`Err(Error)`] if the conversion fails
+            impl #impl_generics #std_path::convert::TryFrom<#type_name_raw> for #enum_name #ty_generics #where_clause {
+                type Error = #crate_path::Error;
+                #inline
+                fn try_from(value: #type_name_raw) -> Result<Self, Self::Error> {
+                    Self::from_value_ref(#try_from_ref)
+                }
+            }
+            #try_from_str_impl
+            #try_from_string_impl
+            #try_from_alias_impl
+            #widen_impl
+        },
+        false => quote! { },
+    };
+    expanded = quote! {
+        #expanded
+        #[automatically_derived]
+        #allow_lints
+        impl #impl_generics #enum_name #ty_generics #where_clause {
+            #inline
+            /// Returns the enum variant with the given value, comparing by reference.
+            /// Same as [`TryFrom`], without requiring an owned value
+            /// 
+            /// # Returns
+            /// 
+            /// * [`Ok(T)`] where `T` is the enum variant
+            /// * [`Err(Error)`] if the conversion fails
+            pub fn from_value_ref(value: &#type_name) -> Result<Self, #crate_path::Error> {
+                #try_from_body
+            }
+            #inline
+            /// Returns the enum variant with the given value, if any. Same as
+            /// [`from_value_ref`](Self::from_value_ref), without the `Error`
+            /// 
+            /// Variants which can not be returned (e.g. with fields) are ignored
+            #[must_use]
+            pub fn which(value: &#type_name) -> Option<Self> {
+                Self::from_value_ref(value).ok()
+            }
+        }
+        #try_from_impl
+        #value_bytes_impl
+        #parse_all_impl
+        #self_eq_impl
+        #ord_impl
+        #hash_impl
+        #borrow_impl
+        #default_impl
+        #all_impl
+        #ffi_impl
+        #deref_trait_impl
+        #display_impl
+        #from_str_impl
+        #[automatically_derived]
+        #allow_lints
+        impl #impl_generics #enum_name #ty_generics #where_clause {
+            #( #predicates )*
+        }
+    };
+    TokenStream::from(expanded)
+}
+
+#[proc_macro_derive(ConstEach, attributes(value, armtype, const_value, const_type, thisenum))]
+/// Add's constants of any type to each arm of an enum
+/// 
+/// To get the value, the type must be explicitly passed
+/// as a generic to [`<enum_name>::value`]. This will automatically
+/// try to convert constant to the expected type using [`std::any::Any`] 
+/// and [`downcast_ref`]. Currently [`TryFrom`] is not supported, so typing
+/// is fairly strict. Upon failure, it will return [`None`].
+/// 
+/// * To get the value as a reference, call the function [`<enum_name>::value`]
+///   or [`<enum_name>::value_as`]
+/// * To get a copy of the value for [`Copy`] types, call `<enum_name>::value_copy`
+/// * Unlike [`Const`], this macro does not enable direct comparison
+///   using [`PartialEq`] when imported using the `eq` feature.
+/// 
+/// The `#[armtype = ...]` attribute is **NOT*** required for this macro to function, 
+/// but ***CAN** be applied to ***each individual arm*** of the enum, since values
+/// are not expected to share a type. If applied to the enum, it is the type of all arms
+/// without their own `#[armtype(...)]`. If no type is given, then the type is
+/// inferred from the literal value in the `#[value = ...]` attribute. Suffixed
+/// literals, given using the list form (e.g. `#[value(3.14f32)]`), are inferred
+/// as the type of their suffix. Negative literals must also use the list form,
+/// e.g. `#[value(-5)]` is inferred as `i32` and `#[value(-2.5)]` as `f64`, and
+/// `bool` literals are inferred as `bool`.
+/// 
+/// All values set will return a [`Option<&'static T>`] reference. To the input type,
+/// of [`T`] AND [`&T`]. If multiple references are used (e.g. `&&T`), then
+/// the return type will be [`Option<&'static &T>`].
+/// 
+/// All unit variants can be iterated using `<enum_name>::variants()`, and the values of all arms
+/// of type `T` collected using `<enum_name>::values::<T>()`.
+/// 
+/// Both [`Const`] and [`ConstEach`] generate a `value` method. To derive both on the
+/// same enum, add `#[thisenum(value_as)]` to the enum, so that [`ConstEach`] only
+/// generates [`<enum_name>::value_as`].
+/// 
+/// Values which are not literals, paths or macros (e.g. `#[value(String::from("a"))]`), as well as
+/// `vec!` and `format!`, are computed on first access, and stored in a [`OnceLock`](std::sync::OnceLock).
+/// Since these are not `'static`, they require `#[armtype(...)]` on the arm (or the enum), and are not
+/// supported with the `no_std` feature.
+/// 
+/// `<enum_name>::from_value::<T>(value)` (or `from_value_as`) returns the first unit variant whose
+/// value is of type `T` and equal to `value`, as an [`Option`].
+/// 
+/// Like [`Const`], `#[const_value ...]` and `#[const_type(...)]` are accepted as aliases
+/// of `#[value ...]` and `#[armtype(...)]`.
+/// 
+/// # Example
+/// 
+/// ```
+/// use thisenum::ConstEach;
+/// 
+/// #[derive(ConstEach, Debug)]
+/// enum MyEnum {
+///     #[armtype(u8)]
+///     #[value = 0xAA]
+///     A,
+///     #[value = "test3"]
+///     B,
 /// }
 /// 
 /// #[derive(ConstEach, Debug)]
@@ -421,148 +1763,1268 @@ pub fn thisenum_const(input: TokenStream) -> TokenStream {
 ///     assert!(MyEnum::B.value::<&str>().is_some());
 ///     assert!(Tags::Data.value::<&[u8]>().is_some());
 /// 
-///     // An infered type. This will be as strict as possible,
-///     // therefore [`&[u8]`] will fail but [`&[u8; 2]`] will succeed
-///     assert!(Tags::Key.value::<&[u8; 2]>().is_some());
-///     assert!(Tags::Key.value::<&[u8; 5]>().is_none());
-///     assert!(Tags::Key.value::<&[u8]>().is_none());
-///     assert!(u16::from_le_bytes(**Tags::Key.value::<&[u8; 2]>().unwrap()) == 0x0100);
+///     // An infered type. This will be as strict as possible,
+///     // therefore [`&[u8]`] will fail but [`&[u8; 2]`] will succeed
+///     assert!(Tags::Key.value::<&[u8; 2]>().is_some());
+///     assert!(Tags::Key.value::<&[u8; 5]>().is_none());
+///     assert!(Tags::Key.value::<&[u8]>().is_none());
+///     assert!(u16::from_le_bytes(**Tags::Key.value::<&[u8; 2]>().unwrap()) == 0x0100);
+/// 
+///     // casting as anything other than the defined / inferred type will
+///     // fail, since this uses [`downcast_ref`] from [`std::any::Any`]
+///     assert!(Tags::Length.value::<u16>().is_some());
+///     assert!(Tags::Length.value::<u32>().is_none());
+///     assert!(Tags::Length.value::<u64>().is_none());
+/// 
+///     // however, can always convert to a different type
+///     // after value is successfully acquired
+///     assert!(*Tags::Length.value::<u16>().unwrap() as u32 == 24250);
+/// }
+/// ```
+pub fn thisenum_const_each(input: TokenStream) -> TokenStream {
+    let name = "ConstEach";
+    let input = parse_macro_input!(input as DeriveInput);
+    let options = match get_enum_options(&input.attrs) {
+        Ok(options) => options,
+        Err(e) => panic!("{}", e),
+    };
+    let std_path = std_path();
+    let alloc_path = alloc_path(&options.crate_path);
+    let allow_lints = allow_lints();
+    // --------------------------------------------------
+    // extract the name, variants, and values
+    // --------------------------------------------------
+    let enum_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let variants = match input.data {
+        Data::Enum(DataEnum { variants, .. }) => variants,
+        _ => panic!("{}", Error::DeriveForNonEnum(name.into())),
+    };
+    // --------------------------------------------------
+    // the enum-level armtype, if any, is the default for
+    // arms without their own
+    // --------------------------------------------------
+    let default_type = match get_type(&input.attrs) {
+        Ok(typ) => typ,
+        Err(e) => panic!("{}", e),
+    };
+    // --------------------------------------------------
+    // only unit variants can be listed, since variants
+    // with fields can not be constructed
+    // --------------------------------------------------
+    let unit_variants = variants
+        .iter()
+        .filter(|variant| matches!(variant.fields, syn::Fields::Unit))
+        .map(|variant| &variant.ident)
+        .collect::<Vec<_>>();
+    // --------------------------------------------------
+    // names of the variants, for panic messages
+    // --------------------------------------------------
+    let variant_name_arms = variants
+        .iter()
+        .map(|variant| {
+            let variant_name = &variant.ident;
+            let args_tokens = match variant.fields {
+                syn::Fields::Unit => quote! { },
+                syn::Fields::Named(_) => quote! { { .. } },
+                syn::Fields::Unnamed(_) => quote! { (..) },
+            };
+            quote! { #enum_name::#variant_name #args_tokens => stringify!(#variant_name), }
+        })
+        .collect::<Vec<_>>();
+    // --------------------------------------------------
+    // computed values (e.g. `vec![1, 2]`) can not be
+    // promoted to `&'static` references, so they require
+    // a type to be stored. report this on the value
+    // --------------------------------------------------
+    let untyped = variants.iter().find_map(|variant| {
+        let value = get_val(name.into(), &variant.attrs).ok()?;
+        match (get_type(&variant.attrs), &default_type) {
+            (Ok(None), None) => get_untyped_computed_error(variant, &value, name).map(|e| e.to_compile_error()),
+            _ => None,
+        }
+    });
+    if let Some(untyped) = untyped {
+        return TokenStream::from(untyped);
+    }
+    // --------------------------------------------------
+    // literals are cast to the type of the arm (e.g. `1`
+    // to `f32`), so report literals which can not be cast
+    // on the value rather than the generated code
+    // --------------------------------------------------
+    let mismatched = variants.iter().find_map(|variant| {
+        let value = get_val(name.into(), &variant.attrs).ok()?;
+        let typ = get_type(&variant.attrs).ok()?.or_else(|| default_type.clone())?;
+        get_uncastable_lit_error(variant, &typ, &value).map(|e| e.to_compile_error())
+    });
+    if let Some(mismatched) = mismatched {
+        return TokenStream::from(mismatched);
+    }
+    // --------------------------------------------------
+    // generate the output tokens
+    // --------------------------------------------------
+    let (variant_code, type_name_code, value_any_code) = variants.iter().map(|variant| {
+        let variant_name = &variant.ident;
+        let variant_name = match variant.fields {
+            syn::Fields::Unit => quote! { #variant_name },
+            syn::Fields::Named(_) => quote! { #variant_name { .. } },
+            syn::Fields::Unnamed(_) => quote! { #variant_name (..) },
+        };
+        let typ = get_type(&variant.attrs).map(|typ| typ.or_else(|| default_type.clone()));
+        match (typ, get_val(name.into(), &variant.attrs)) {
+            (Err(e), _) => panic!("{}", e),
+            // ------------------------------------------------
+            // computed values (e.g. `#[value(String::from("a"))]`)
+            // are not promoted to `&'static` references, so they
+            // are stored in a `OnceLock` on first access
+            // ------------------------------------------------
+            (Ok(Some(typ)), Ok(value)) if is_computed_value(&value) => {
+                if cfg!(feature = "no_std") {
+                    panic!("{}", Error::ComputedValueNoStd(variant.ident.to_string()));
+                }
+                (
+                    quote! { #enum_name::#variant_name => self.value_any().downcast_ref::<__T>(), },
+                    quote! { #enum_name::#variant_name => #std_path::any::type_name::<#typ>(), },
+                    quote! {
+                        {
+                            static VALUE: ::std::sync::OnceLock<#typ> = ::std::sync::OnceLock::new();
+                            VALUE.get_or_init(|| #value)
+                        }
+                    },
+                )
+            },
+            (Ok(None), Ok(value)) if is_computed_value(&value) => panic!("{}", Error::MissingArmType(format!("for the computed value of `{}`", variant.ident), name.into())),
+            // ------------------------------------------------
+            // if type is specified, use it
+            // ------------------------------------------------
+            (Ok(Some(typ)), Ok(value)) => (
+                quote! {
+                    #enum_name::#variant_name => {
+                        let val: &dyn #std_path::any::Any = &(#value as #typ);
+                        val.downcast_ref::<__T>()
+                    },
+                },
+                quote! { #enum_name::#variant_name => #std_path::any::type_name::<#typ>(), },
+                quote! { &(#value as #typ) },
+            ),
+            // ------------------------------------------------
+            // no type specified, try to infer
+            // ------------------------------------------------
+            (Ok(None), Ok(value)) => (
+                quote! {
+                    #enum_name::#variant_name => {
+                        let val: &dyn #std_path::any::Any = &#value;
+                        val.downcast_ref::<__T>()
+                    },
+                },
+                quote! { #enum_name::#variant_name => type_name_of(&#value), },
+                quote! { &#value },
+            ),
+            // ------------------------------------------------
+            // unable to infer type
+            // ------------------------------------------------
+            (_, Err(_)) => (
+                quote! { #enum_name::#variant_name => None, },
+                quote! { #enum_name::#variant_name => #std_path::any::type_name::<()>(), },
+                quote! { &() },
+            ),
+        }
+    }).unzip_n_vec();
+    // ------------------------------------------------
+    // values are looked up by the index of the arm, so
+    // that computed values are only stored once
+    // ------------------------------------------------
+    let value_indices = (0..value_any_code.len()).collect::<Vec<_>>();
+    let num_values = value_indices.len();
+    let value_patterns = variants
+        .iter()
+        .map(|variant| {
+            let variant_name = &variant.ident;
+            quote! { #enum_name::#variant_name { .. } }
+        })
+        .collect::<Vec<_>>();
+    // ------------------------------------------------
+    // empty enums match on `*self`, since `&<enum_name>`
+    // is not considered empty
+    // ------------------------------------------------
+    let match_self = match variants.is_empty() {
+        true => quote! { *self },
+        false => quote! { self },
+    };
+    // ------------------------------------------------
+    // `value` collides with the one from `Const`, so it
+    // can be omitted using `#[thisenum(value_as)]`
+    // ------------------------------------------------
+    let value_impl = match options.value_as {
+        true => quote! { },
+        false => quote! {
+            #[inline]
+            /// Returns the value of the enum variant defined by [`ConstEach`],
+            /// if it is of type `T`. Same as `value_as`
+            #[must_use]
+            pub fn value<__T: 'static>(&self) -> Option<&'static __T> {
+                self.value_as::<__T>()
+            }
+        },
+    };
+    // ------------------------------------------------
+    // return
+    // ------------------------------------------------
+    let expanded = quote! {
+        #[automatically_derived]
+        #allow_lints
+        #[doc = concat!(" [`ConstEach`] implementation for [`", stringify!(#enum_name), "`]")]
+        impl #impl_generics #enum_name #ty_generics #where_clause {
+            /// Returns the value of the enum variant defined by [`ConstEach`],
+            /// if it is of type `T`
+            #[must_use]
+            pub fn value_as<__T: 'static>(&self) -> Option<&'static __T> {
+                match self {
+                    #( #variant_code )*
+                    _ => None,
+                }
+            }
+            #value_impl
+            /// Returns a copy of the value of the enum variant defined by
+            /// [`ConstEach`], if it is of type `T`. Same as `value_as`, without
+            /// the reference
+            #[must_use]
+            pub fn value_copy<__T: #std_path::marker::Copy + 'static>(&self) -> Option<__T> {
+                self.value_as::<__T>().copied()
+            }
+            /// Returns the first unit variant whose value is of type `T` and
+            /// equal to the given value, if any
+            ///
+            /// Arms with values of other types are never returned
+            #[must_use]
+            pub fn from_value_as<__T: PartialEq + 'static>(value: __T) -> Option<Self> {
+                #( if Self::#unit_variants.value_as::<__T>() == Some(&value) { return Some(Self::#unit_variants); } )*
+                None
+            }
+            #[inline]
+            /// Returns the first unit variant whose value is of type `T` and
+            /// equal to the given value, if any. Same as `from_value_as`
+            #[must_use]
+            pub fn from_value<__T: PartialEq + 'static>(value: __T) -> Option<Self> {
+                Self::from_value_as::<__T>(value)
+            }
+            /// Returns the value of the enum variant defined by [`ConstEach`],
+            /// which must be of type `T`
+            ///
+            /// # Panics
+            ///
+            /// If the variant has no value of type `T`, naming the variant and
+            /// both the requested type and the type of the value
+            #[must_use]
+            #[track_caller]
+            pub fn value_unwrap<__T: 'static>(&self) -> &'static __T {
+                match self.value_as::<__T>() {
+                    Some(value) => value,
+                    None => panic!(
+                        "`{}::{}` has no value of type `{}`, found `{}`",
+                        stringify!(#enum_name),
+                        self.__thisenum_variant_name(),
+                        #std_path::any::type_name::<__T>(),
+                        self.type_name(),
+                    ),
+                }
+            }
+            /// Returns the value of the enum variant defined by [`ConstEach`],
+            /// as [`Any`](core::any::Any) so that it can be inspected or downcast
+            ///
+            /// If the variant has no value, returns `&()`
+            #[must_use]
+            pub fn value_any(&self) -> &'static dyn #std_path::any::Any {
+                match #match_self {
+                    #( #value_patterns => Self::__thisenum_value_any(#value_indices), )*
+                }
+            }
+            /// Returns the values of all arms defined by [`ConstEach`] which are of
+            /// type `T`, in order of declaration
+            ///
+            /// Unlike [`variants`](Self::variants), this includes arms with fields
+            #[must_use]
+            pub fn values<__T: 'static>() -> #alloc_path::vec::Vec<&'static __T> {
+                (0..#num_values)
+                    .filter_map(|index| Self::__thisenum_value_any(index).downcast_ref::<__T>())
+                    .collect()
+            }
+            /// Applies `f` to the values of all arms defined by [`ConstEach`] which
+            /// are of type `T`, in order of declaration. Same as `values`, followed by `f`
+            #[must_use]
+            pub fn map<__T: 'static, __R>(f: impl Fn(&__T) -> __R) -> #alloc_path::vec::Vec<__R> {
+                (0..#num_values)
+                    .filter_map(|index| Self::__thisenum_value_any(index).downcast_ref::<__T>())
+                    .map(f)
+                    .collect()
+            }
+            #[doc(hidden)]
+            fn __thisenum_variant_name(&self) -> &'static str {
+                match #match_self {
+                    #( #variant_name_arms )*
+                }
+            }
+            #[doc(hidden)]
+            fn __thisenum_value_any(index: usize) -> &'static dyn #std_path::any::Any {
+                match index {
+                    #( #value_indices => #value_any_code, )*
+                    _ => &(),
+                }
+            }
+            /// Returns the name of the type of the value of the enum variant
+            /// defined by [`ConstEach`], using [`type_name`](core::any::type_name)
+            ///
+            /// Useful to debug why `value_as::<T>()` returns [`None`]. If the
+            /// variant has no value, returns the name of `()`
+            #[must_use]
+            pub fn type_name(&self) -> &'static str {
+                #[allow(dead_code)]
+                fn type_name_of<__T>(_: &__T) -> &'static str {
+                    #std_path::any::type_name::<__T>()
+                }
+                match #match_self {
+                    #( #type_name_code )*
+                }
+            }
+            /// Returns all unit variants of the enum, in order of declaration
+            ///
+            /// Variants with fields are not included
+            #[must_use]
+            pub fn variants() -> &'static [Self] where Self: 'static {
+                &[ #( #enum_name::#unit_variants ),* ]
+            }
+        }
+    };
+    TokenStream::from(expanded)
+}
+
+/// Helper function to get the path of the standard library used in generated code
+///
+/// # Output
+///
+/// * `::core` if the `no_std` feature is enabled
+/// * `::std` otherwise
+fn std_path() -> proc_macro2::TokenStream {
+    match cfg!(feature = "no_std") {
+        true => quote! { ::core },
+        false => quote! { ::std },
+    }
+}
+
+/// Helper function to get the path of the `alloc` crate used in generated code,
+/// e.g. for `format!` and `Vec`
+///
+/// # Output
+///
+/// * `<crate_path>::__private::alloc` if the `no_std` feature is enabled
+/// * `::std` otherwise
+fn alloc_path(crate_path: &syn::Path) -> proc_macro2::TokenStream {
+    match cfg!(feature = "no_std") {
+        true => quote! { #crate_path::__private::alloc },
+        false => quote! { ::std },
+    }
+}
+
+/// Helper function to get the lints allowed on generated implementations, so that
+/// crates denying e.g. `clippy::all` or `clippy::pedantic` are not broken by generated code
+fn allow_lints() -> proc_macro2::TokenStream {
+    quote! {
+        #[allow(
+            clippy::from_over_into,
+            clippy::derivable_impls,
+            clippy::match_like_matches_macro,
+            clippy::float_cmp,
+        )]
+    }
+}
+
+/// Options applied to the enum, aka `#[thisenum(...)]`
+struct EnumOptions {
+    /// Path to the `thisenum` crate used in generated code, aka `#[thisenum(crate = <path>)]`
+    /// 
+    /// Defaults to `::thisenum`
+    crate_path: syn::Path,
+    /// Only generate `value_as` for [`ConstEach`], aka `#[thisenum(value_as)]`
+    /// 
+    /// Required when also deriving [`Const`], since both generate `value`
+    value_as: bool,
+    /// Case convention applied to variant names in name-based conversions,
+    /// aka `#[thisenum(rename_all = "<case>")]`, see [`rename_ident`]
+    rename_all: Option<String>,
+    /// Derive [`TryFrom`] for [`Const`], aka `#[thisenum(derive_try_from = <bool>)]`
+    /// 
+    /// Defaults to `true`
+    derive_try_from: bool,
+    /// Fail to compile if any two values of [`Const`] are the same, aka `#[thisenum(unique)]`
+    unique: bool,
+    /// Return `Error::InvalidValueWithCandidates` from a failed [`TryFrom`] for [`Const`],
+    /// aka `#[thisenum(verbose_errors)]`
+    verbose_errors: bool,
+}
+
+impl Default for EnumOptions {
+    fn default() -> Self {
+        EnumOptions {
+            crate_path: syn::parse_quote! { ::thisenum },
+            value_as: false,
+            rename_all: None,
+            derive_try_from: true,
+            unique: false,
+            verbose_errors: false,
+        }
+    }
+}
+
+/// Helper function to extract the options from the [`Attribute`]s, aka `#[thisenum(...)]`
+///
+/// # Input
+///
+/// ```text
+/// #[thisenum(crate = <path>, value_as, unique, rename_all = "<case>", derive_try_from = <bool>)]
+/// ```
+///
+/// # Output
+///
+/// [`EnumOptions`] with the defaults for any options not present, or [`Err`] if the attribute is invalid
+fn get_enum_options(attrs: &[Attribute]) -> Result<EnumOptions, Error> {
+    let mut options = EnumOptions::default();
+    for attr in attrs {
+        if !attr.path.is_ident("thisenum") { continue; }
+        attr.parse_args_with(|input: syn::parse::ParseStream| {
+            while !input.is_empty() {
+                if input.peek(syn::Token![crate]) {
+                    input.parse::<syn::Token![crate]>()?;
+                    input.parse::<syn::Token![=]>()?;
+                    options.crate_path = input.parse::<syn::Path>()?;
+                } else {
+                    let ident = input.parse::<syn::Ident>()?;
+                    match ident.to_string().as_str() {
+                        "value_as" => options.value_as = true,
+                        "unique" => options.unique = true,
+                        "verbose_errors" => options.verbose_errors = true,
+                        "rename_all" => {
+                            input.parse::<syn::Token![=]>()?;
+                            let case = input.parse::<syn::LitStr>()?;
+                            if !RENAME_ALL.contains(&case.value().as_str()) {
+                                return Err(syn::Error::new(case.span(), format!("unknown case `{}`, expected one of {:?}", case.value(), RENAME_ALL)));
+                            }
+                            options.rename_all = Some(case.value());
+                        },
+                        "derive_try_from" => {
+                            input.parse::<syn::Token![=]>()?;
+                            options.derive_try_from = input.parse::<syn::LitBool>()?.value;
+                        },
+                        _ => return Err(syn::Error::new(ident.span(), format!("unknown option `{}`", ident))),
+                    }
+                }
+                if input.is_empty() { break; }
+                input.parse::<syn::Token![,]>()?;
+            }
+            Ok(())
+        })
+        .map_err(|e| Error::InvalidEnumOption(e.to_string()))?;
+    }
+    Ok(options)
+}
+
+/// Case conventions supported by `#[thisenum(rename_all = "<case>")]`
+const RENAME_ALL: [&str; 5] = ["snake_case", "kebab-case", "SCREAMING_SNAKE_CASE", "lowercase", "UPPERCASE"];
+
+/// Helper function to get the name of a variant used in name-based conversions,
+/// aka `display = name` and `from_str = name`
+///
+/// # Output
+///
+/// The identifier of the variant, converted to the case of `rename_all` if specified
+/// (see [`RENAME_ALL`]), e.g. `FooBar` is `foo-bar` for `kebab-case`
+fn rename_ident(ident: &syn::Ident, rename_all: Option<&str>) -> String {
+    let ident = ident.to_string();
+    match rename_all {
+        Some("snake_case") => to_snake_case(&ident),
+        Some("kebab-case") => to_snake_case(&ident).replace('_', "-"),
+        Some("SCREAMING_SNAKE_CASE") => to_snake_case(&ident).to_uppercase(),
+        Some("lowercase") => ident.to_lowercase(),
+        Some("UPPERCASE") => ident.to_uppercase(),
+        _ => ident,
+    }
+}
+
+/// Helper function to get the name of a variant used in name-based conversions
+///
+/// # Input
+///
+/// ```text
+/// #[rename = "<name>"]
+/// ```
+///
+/// # Output
+///
+/// The `<name>` if the variant has a `#[rename = ...]` attribute, otherwise the
+/// identifier converted with [`rename_ident`]. [`Err`] if the attribute is not a string literal
+fn get_name(variant: &syn::Variant, rename_all: Option<&str>) -> Result<String, Error> {
+    match variant.attrs.iter().find(|attr| attr.path.is_ident("rename")) {
+        Some(attr) => match attr.parse_meta() {
+            Ok(Meta::NameValue(MetaNameValue { lit: syn::Lit::Str(name), .. })) => Ok(name.value()),
+            _ => Err(Error::InvalidRename(variant.ident.to_string())),
+        },
+        None => Ok(rename_ident(&variant.ident, rename_all)),
+    }
+}
+
+/// Helper function to check if an [`Attribute`] is `#[value ...]`, or its alias `#[const_value ...]`
+fn is_value_attr(attr: &Attribute) -> bool {
+    attr.path.is_ident("value") || attr.path.is_ident("const_value")
+}
+
+/// Helper function to check if an [`Attribute`] is `#[armtype(...)]`, or its alias `#[const_type(...)]`
+fn is_armtype_attr(attr: &Attribute) -> bool {
+    attr.path.is_ident("armtype") || attr.path.is_ident("const_type")
+}
+
+/// Helper function to extract the value from a [`MetaNameValue`], aka `#[value = <value>]`
+///
+/// Non-literal expressions are also accepted using the list form, aka `#[value(<expr>)]`
+///
+/// The attribute may appear anywhere among the other attributes of the variant
+/// (e.g. after doc comments or `#[armtype(...)]`), the first one found is used
+///
+/// # Input
+///
+/// ```text
+/// #[value = <value>]
+/// #[value(<expr>)]
+/// ```
+///
+/// # Output
+///
+/// [`TokenStream`] containing the value `<value>`, or [`Err`] if the attribute is not present / invalid
+fn get_val(name: String, attrs: &[Attribute]) -> Result<proc_macro2::TokenStream, Error> {
+    for attr in attrs {
+        if !is_value_attr(attr) { continue; }
+        match attr.parse_meta() {
+            Ok(meta) => match meta {
+                Meta::NameValue(MetaNameValue { lit, .. }) => return Ok(lit.into_token_stream()),
+                Meta::List(list) => {
+                    let tokens = list.nested.iter().map(|nested_meta| {
+                        match nested_meta {
+                            syn::NestedMeta::Lit(lit) => lit.to_token_stream(),
+                            syn::NestedMeta::Meta(meta) => meta.to_token_stream(),
+                        }
+                    });
+                    // --------------------------------------------------
+                    // multiple items (e.g. `#[value(1, 2, 3)]`) are a
+                    // slice of the items, e.g. for `&[u8]` armtypes
+                    // --------------------------------------------------
+                    return match list.nested.len() {
+                        1 => Ok(quote! { #( #tokens )* }),
+                        _ => Ok(quote! { &[ #( #tokens ),* ] }),
+                    };
+                }
+                Meta::Path(_) => return Ok(meta.into_token_stream())
+            },
+            Err(_) => {
+                // --------------------------------------------------
+                // non-literals (e.g. `200..=299` or `compute(1)`) and
+                // negative literals (e.g. `-0x7f`) are not valid meta,
+                // so `parse_meta` fails on them. parse them as an
+                // expression, where negative literals are still
+                // treated as literals (see `is_lit_value`)
+                // --------------------------------------------------
+                return match get_expr_val(attr) {
+                    Some(expr) => Ok(expr),
+                    None => Err(Error::NonLiteralValue),
+                };
+            },
+        }
+    }
+    Err(Error::MissingValue(name))
+}
+
+/// Options parsed from the modifiers of `#[armtype(<type>, <modifiers>...)]`
+///
+/// Each derive is responsible for validating the modifiers it supports,
+/// see [`ArmTypeOptions::unknown`]
+#[derive(Default)]
+struct ArmTypeOptions {
+    /// The modifiers, in the order they were specified
+    modifiers: Vec<syn::Ident>,
+    /// The `<name> = <expr>` modifiers, in the order they were specified
+    values: Vec<(syn::Ident, syn::Expr)>,
+}
+
+impl ArmTypeOptions {
+    /// Returns `true` if the modifier `name` was specified
+    fn has(&self, name: &str) -> bool {
+        self.modifiers.iter().any(|m| m == name)
+    }
+
+    /// Returns the expression of the modifier `name = <expr>`, if specified
+    fn get(&self, name: &str) -> Option<&syn::Expr> {
+        self.values.iter().find(|(m, _)| m == name).map(|(_, expr)| expr)
+    }
+
+    /// Returns the first modifier that is not in `supported`, if any
+    fn unknown(&self, supported: &[&str]) -> Option<&syn::Ident> {
+        self.modifiers
+            .iter()
+            .chain(self.values.iter().map(|(m, _)| m))
+            .find(|m| !supported.iter().any(|s| *m == s))
+    }
+}
+
+/// Helper function to parse `#[armtype(<type>, <modifiers>...)]` from the [`Attribute`]s
+/// 
+/// Will indicate whether or not the type should be dereferenced or not. Useful
+/// for the [`Const`] macro
+///
+/// # Input
+///
+/// ```text
+/// #[armtype(<type>, <modifier>, <modifier>, ...)]
+/// ```
+///
+/// # Output
+///
+/// [`None`] if the attribute is not present, or [`Err`] if the attribute is invalid
 /// 
-///     // casting as anything other than the defined / inferred type will
-///     // fail, since this uses [`downcast_ref`] from [`std::any::Any`]
-///     assert!(Tags::Length.value::<u16>().is_some());
-///     assert!(Tags::Length.value::<u32>().is_none());
-///     assert!(Tags::Length.value::<u64>().is_none());
+/// Otherwise a tuple:
 /// 
-///     // however, can always convert to a different type
-///     // after value is successfully acquired
-///     assert!(*Tags::Length.value::<u16>().unwrap() as u32 == 24250);
-/// }
+/// * 0 - [`Type`] containing the type `<type>` (already de-referenced)
+/// * 1 - An additional flag that indicates if the type has been de-referenced
+/// * 2 - [`ArmTypeOptions`] containing the modifiers
+fn parse_armtype(attrs: &[Attribute]) -> Result<Option<(Type, bool, ArmTypeOptions)>, Error> {
+    for attr in attrs {
+        if !is_armtype_attr(attr) { continue; }
+        return match parse_armtype_args(attr) {
+            Ok((typ, options)) => {
+                let (typ, deref) = split_deref_type(typ);
+                Ok(Some((typ, deref, options)))
+            },
+            Err(e) => Err(Error::InvalidArmType(e.to_string())),
+        };
+    }
+    Ok(None)
+}
+
+/// Helper function to construct a variant with fields from the [`Attribute`], aka `#[fill(...)]`
+///
+/// # Input
+///
+/// ```text
+/// #[fill(Default)]
+/// #[fill(<field> = <expr>, <field> = <expr>, ...)]
+/// #[fill(Default, <field> = <expr>, ...)]
 /// ```
-pub fn thisenum_const_each(input: TokenStream) -> TokenStream {
-    let name = "ConstEach";
-    let input = parse_macro_input!(input as DeriveInput);
-    // --------------------------------------------------
-    // extract the name, variants, and values
-    // --------------------------------------------------
-    let enum_name = &input.ident;
-    let variants = match input.data {
-        Data::Enum(DataEnum { variants, .. }) => variants,
-        _ => panic!("{}", Error::DeriveForNonEnum(name.into())),
+///
+/// # Output
+///
+/// [`None`] if the attribute is not present, or [`Err`] if the attribute is invalid
+/// or a field is missing without `Default`
+///
+/// Otherwise [`Some`] containing the construction, e.g. `Enum::Variant { a: 1, b: 2 }`
+fn get_fill(enum_name: &syn::Ident, variant: &syn::Variant) -> Result<Option<proc_macro2::TokenStream>, Error> {
+    let variant_name = &variant.ident;
+    let attr = match variant.attrs.iter().find(|attr| attr.path.is_ident("fill")) {
+        Some(attr) => attr,
+        None => return Ok(None),
     };
+    let invalid = |msg: String| Error::InvalidFill(variant_name.to_string(), msg);
     // --------------------------------------------------
-    // generate the output tokens
+    // parse `Default` and `<field> = <expr>` entries
     // --------------------------------------------------
-    let variant_code = variants.iter().map(|variant| {
-        let variant_name = &variant.ident;
-        match (get_type(&variant.attrs), get_val(name.into(), &variant.attrs)) {
-            // ------------------------------------------------
-            // if type is specified, use it
-            // ------------------------------------------------
-            (Some(typ), Ok(value)) => quote! {
-                #enum_name::#variant_name => {
-                    let val: &dyn ::std::any::Any = &(#value as #typ);
-                    val.downcast_ref::<T>()
-                },
+    let (default, assigned) = attr
+        .parse_args_with(|input: syn::parse::ParseStream| {
+            let mut default = false;
+            let mut assigned: Vec<(syn::Ident, syn::Expr)> = Vec::new();
+            while !input.is_empty() {
+                let ident = input.parse::<syn::Ident>()?;
+                match input.peek(syn::Token![=]) {
+                    true => {
+                        input.parse::<syn::Token![=]>()?;
+                        assigned.push((ident, input.parse::<syn::Expr>()?));
+                    },
+                    false if ident == "Default" => default = true,
+                    false => return Err(syn::Error::new(ident.span(), "expected `Default` or `<field> = <expr>`")),
+                }
+                if input.is_empty() { break; }
+                input.parse::<syn::Token![,]>()?;
+            }
+            Ok((default, assigned))
+        })
+        .map_err(|e| invalid(e.to_string()))?;
+    let std_path = std_path();
+    let default_value = quote! { #std_path::default::Default::default() };
+    match &variant.fields {
+        syn::Fields::Unit => Err(invalid("variant has no fields".into())),
+        syn::Fields::Unnamed(syn::FieldsUnnamed { unnamed, .. }) => match (default, assigned.first()) {
+            (true, None) => {
+                let fields = unnamed.iter().map(|_| &default_value);
+                Ok(Some(quote! { #enum_name::#variant_name( #( #fields ),* ) }))
+            },
+            (_, Some((ident, _))) => Err(invalid(format!("unnamed fields can only be filled using `Default`, found `{}`", ident))),
+            (false, None) => Err(invalid("expected `Default` or `<field> = <expr>`".into())),
+        },
+        syn::Fields::Named(syn::FieldsNamed { named, .. }) => {
+            if let Some((ident, _)) = assigned.iter().find(|(ident, _)| !named.iter().any(|field| field.ident.as_ref() == Some(ident))) {
+                return Err(invalid(format!("unknown field `{}`", ident)));
+            }
+            let fields = named
+                .iter()
+                .filter_map(|field| field.ident.as_ref())
+                .map(|field| match (assigned.iter().find(|(ident, _)| ident == field), default) {
+                    (Some((_, expr)), _) => Ok(quote! { #field: #expr }),
+                    (None, true) => Ok(quote! { #field: #default_value }),
+                    (None, false) => Err(Error::MissingFillField(variant_name.to_string(), field.to_string())),
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            Ok(Some(quote! { #enum_name::#variant_name { #( #fields ),* } }))
+        },
+    }
+}
+
+/// Helper function to check if a variant only holds [`PhantomData`](core::marker::PhantomData),
+/// aka `_Phantom(PhantomData<T>)`, and has no `#[value(...)]` attribute
+fn is_phantom_variant(variant: &syn::Variant) -> bool {
+    !variant.fields.is_empty()
+        && !variant.attrs.iter().any(is_value_attr)
+        && variant.fields.iter().all(|field| match &field.ty {
+            Type::Path(syn::TypePath { path, .. }) => path.segments.last().is_some_and(|segment| segment.ident == "PhantomData"),
+            _ => false,
+        })
+}
+
+/// Helper function to get the start of `auto` from `#[armtype(<type>, auto = ...)]`
+///
+/// # Input
+///
+/// ```text
+/// auto = increment
+/// auto = increment(<start>)
+/// ```
+///
+/// # Output
+///
+/// The start of the counter, `0` if not specified
+fn get_auto_start(auto: &syn::Expr) -> Result<i128, Error> {
+    match auto {
+        syn::Expr::Path(syn::ExprPath { path, .. }) if path.is_ident("increment") => Ok(0),
+        syn::Expr::Call(syn::ExprCall { func, args, .. }) if args.len() == 1 && matches!(&**func, syn::Expr::Path(syn::ExprPath { path, .. }) if path.is_ident("increment")) => {
+            get_int_val(&args[0].to_token_stream()).ok_or_else(|| Error::InvalidAuto("expected an integer literal in `increment(<start>)`".into()))
+        },
+        _ => Err(Error::InvalidAuto("expected `increment` or `increment(<start>)`".into())),
+    }
+}
+
+/// Helper function to get the value of an integer literal, aka `5` or `-5`
+///
+/// Values above [`i128::MAX`] are not supported, see [`get_uint_val`]
+fn get_int_val(value: &proc_macro2::TokenStream) -> Option<i128> {
+    match syn::parse2::<syn::Expr>(value.clone()).ok()? {
+        syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(lit), .. }) => lit.base10_parse::<i128>().ok(),
+        syn::Expr::Unary(syn::ExprUnary { op: syn::UnOp::Neg(_), expr, .. }) => match *expr {
+            syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(lit), .. }) => lit
+                .base10_parse::<u128>()
+                .ok()
+                .filter(|v| *v <= i128::MIN.unsigned_abs())
+                .map(|v| (v as i128).wrapping_neg()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Helper function to get the value of an unsigned integer literal, aka `5`
+///
+/// Unlike [`get_int_val`], this supports values above [`i128::MAX`], aka all of `u128`
+fn get_uint_val(value: &proc_macro2::TokenStream) -> Option<u128> {
+    match syn::parse2::<syn::Expr>(value.clone()).ok()? {
+        syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(lit), .. }) => lit.base10_parse::<u128>().ok(),
+        _ => None,
+    }
+}
 
+/// Helper function to get the values of all variants with `auto = increment`
+///
+/// Like C enums, variants without `#[value = ...]` are assigned the previous value
+/// plus one, while variants with an integer `#[value = ...]` reset the counter
+///
+/// # Output
+///
+/// The values of all variants, or [`Err`] spanned on the first variant whose assigned
+/// value overflows, or is out of range for the armtype
+fn get_auto_vals(
+    name: &str,
+    variants: &syn::punctuated::Punctuated<syn::Variant, syn::token::Comma>,
+    start: i128,
+    typ: &Type,
+) -> Result<Vec<proc_macro2::TokenStream>, syn::Error> {
+    let primitive = get_primitive(typ).unwrap_or_default();
+    let (min, max) = match (primitive.starts_with('i'), primitive.get(1..).and_then(|bits| bits.parse::<u32>().ok())) {
+        (false, Some(128) | None) => (0, i128::MAX),
+        (false, Some(bits)) => (0, (1i128 << bits) - 1),
+        (true, Some(bits)) => (i128::MIN >> (128 - bits), i128::MAX >> (128 - bits)),
+        (true, None) => (i128::MIN, i128::MAX),
+    };
+    let mut next = Some(start);
+    variants
+        .iter()
+        .map(|variant| match variant.attrs.iter().any(is_value_attr) {
+            true => {
+                let value = get_val(name.into(), &variant.attrs).map_err(|e| syn::Error::new_spanned(variant, e))?;
+                match (get_int_val(&value), get_uint_val(&value)) {
+                    (Some(v), _) => next = v.checked_add(1),
+                    (None, Some(_)) => next = None,
+                    (None, None) => (),
+                }
+                Ok(value)
             },
-            // ------------------------------------------------
-            // no type specified, try to infer
-            // ------------------------------------------------
-            (None, Ok(value)) => quote! {
-                #enum_name::#variant_name => {
-                    let val: &dyn ::std::any::Any = &#value;
-                    val.downcast_ref::<T>()
-                },
+            false => {
+                let current = next.filter(|v| (min..=max).contains(v)).ok_or_else(|| {
+                    let e = Error::InvalidAuto(format!("value of variant `{}` is out of range for `{}`", variant.ident, typ.to_token_stream()));
+                    syn::Error::new_spanned(&variant.ident, e)
+                })?;
+                let value = proc_macro2::Literal::u128_unsuffixed(current.unsigned_abs());
+                let value = match current < 0 {
+                    true => quote! { -#value },
+                    false => quote! { #value },
+                };
+                next = current.checked_add(1);
+                Ok(value)
             },
-            // ------------------------------------------------
-            // unable to infer type
-            // ------------------------------------------------
-            (_, Err(_)) => quote! { #enum_name::#variant_name => None, },
+        })
+        .collect()
+}
+
+/// Helper function to parse the integer type and endianness, aka `from = <int>_<endian>`
+///
+/// # Input
+///
+/// ```text
+/// from = u16_be
+/// from = i32_le
+/// ```
+///
+/// # Output
+///
+/// A tuple of the number of bits, whether the integer is signed, and whether it is big-endian
+fn get_from_int(from: &syn::Expr) -> Result<(u32, bool, bool), Error> {
+    let invalid = || Error::InvalidFrom("expected an integer type and endianness, e.g. `u16_be` or `i32_le`".into());
+    let from = match from {
+        syn::Expr::Path(syn::ExprPath { path, .. }) => path.get_ident().ok_or_else(invalid)?.to_string(),
+        _ => return Err(invalid()),
+    };
+    let (int, be) = match from.split_once('_') {
+        Some((int, "be")) => (int, true),
+        Some((int, "le")) => (int, false),
+        _ => return Err(invalid()),
+    };
+    let signed = match int.get(..1) {
+        Some("u") => false,
+        Some("i") => true,
+        _ => return Err(invalid()),
+    };
+    match int[1..].parse::<u32>() {
+        Ok(bits @ (8 | 16 | 32 | 64 | 128)) => Ok((bits, signed, be)),
+        _ => Err(invalid()),
+    }
+}
+
+/// Helper function to convert an integer value to a byte string literal, see [`get_from_int`]
+///
+/// Catch-all values (aka `#[value(_)]`) are left as-is
+fn int_to_bytes(variant: &syn::Variant, value: proc_macro2::TokenStream, bits: u32, signed: bool, be: bool) -> Result<proc_macro2::TokenStream, Error> {
+    if is_wildcard_value(&value) { return Ok(value); }
+    let int = format!("{}{}", if signed { "i" } else { "u" }, bits);
+    let invalid = || Error::InvalidFrom(format!("value of variant `{}` is not a `{}` integer literal", variant.ident, int));
+    let v = match signed {
+        false => get_uint_val(&value)
+            .filter(|v| bits == 128 || v >> bits == 0)
+            .map(u128::to_be_bytes),
+        true => get_int_val(&value)
+            .filter(|v| (i128::MIN >> (128 - bits)..=i128::MAX >> (128 - bits)).contains(v))
+            .map(i128::to_be_bytes),
+    }.ok_or_else(invalid)?;
+    let len = (bits / 8) as usize;
+    let bytes = match be {
+        true => v[16 - len..].to_vec(),
+        false => v[16 - len..].iter().rev().copied().collect(),
+    };
+    Ok(syn::LitByteStr::new(&bytes, value.into_iter().next().map_or_else(proc_macro2::Span::call_site, |t| t.span())).into_token_stream())
+}
+
+/// Helper function to parse the transform of variant names into values, aka `value = <transform>`
+///
+/// # Input
+///
+/// ```text
+/// value = ident
+/// value = ident_lower
+/// value = ident_snake
+/// ```
+///
+/// # Output
+///
+/// The name of the transform, or [`Err`] if it is not supported
+fn get_ident_transform(value: &syn::Expr) -> Result<&'static str, Error> {
+    const TRANSFORMS: [&str; 3] = ["ident", "ident_lower", "ident_snake"];
+    match value {
+        syn::Expr::Path(syn::ExprPath { path, .. }) => path
+            .get_ident()
+            .and_then(|ident| TRANSFORMS.iter().find(|t| ident == *t))
+            .copied()
+            .ok_or_else(|| Error::InvalidIdentValue("expected `ident`, `ident_lower` or `ident_snake`".into())),
+        _ => Err(Error::InvalidIdentValue("expected `ident`, `ident_lower` or `ident_snake`".into())),
+    }
+}
+
+/// Helper function to get the values of all variants with `value = <transform>`,
+/// see [`get_ident_transform`]
+///
+/// Variants with `#[value = ...]` keep their value, while all other variants are
+/// assigned their name as a string literal, e.g. `FooBar` is `"FooBar"`, `"foobar"`
+/// or `"foo_bar"` for `ident`, `ident_lower` and `ident_snake` respectively
+fn get_ident_vals(
+    name: &str,
+    variants: &syn::punctuated::Punctuated<syn::Variant, syn::token::Comma>,
+    transform: &str,
+) -> Result<Vec<proc_macro2::TokenStream>, Error> {
+    variants
+        .iter()
+        .map(|variant| match variant.attrs.iter().any(is_value_attr) {
+            true => get_val(name.into(), &variant.attrs),
+            false => {
+                let ident = variant.ident.to_string();
+                let value = match transform {
+                    "ident_lower" => ident.to_lowercase(),
+                    "ident_snake" => to_snake_case(&ident),
+                    _ => ident,
+                };
+                Ok(syn::LitStr::new(&value, variant.ident.span()).into_token_stream())
+            },
+        })
+        .collect()
+}
+
+/// Helper function to convert an identifier to snake case, aka `FooBar` to `foo_bar`
+///
+/// Acronyms are kept together, e.g. `HTTPCode` is `http_code`
+fn to_snake_case(ident: &str) -> String {
+    let chars = ident.chars().collect::<Vec<_>>();
+    let mut snake = String::new();
+    for (i, c) in chars.iter().enumerate() {
+        let prev = i.checked_sub(1).map(|i| chars[i]);
+        let next = chars.get(i + 1);
+        let boundary = c.is_uppercase() && match prev {
+            Some(prev) if prev.is_lowercase() || prev.is_ascii_digit() => true,
+            Some(prev) if prev.is_uppercase() => next.is_some_and(|next| next.is_lowercase()),
+            _ => false,
+        };
+        if boundary { snake.push('_'); }
+        snake.extend(c.to_lowercase());
+    }
+    snake
+}
+
+/// Helper function to get the wider integer [`Type`]s of the same signedness, aka
+/// `u16`, `u32`, `u64` and `u128` for `u8`. Empty for non-integer types
+fn get_wider_types(typ: &Type) -> Vec<Type> {
+    const UNSIGNED: [&str; 5] = ["u8", "u16", "u32", "u64", "u128"];
+    const SIGNED: [&str; 5] = ["i8", "i16", "i32", "i64", "i128"];
+    let typ = match get_primitive(typ) {
+        Some(typ) => typ,
+        None => return Vec::new(),
+    };
+    [UNSIGNED, SIGNED]
+        .iter()
+        .find_map(|types| types.iter().position(|t| *t == typ).map(|i| &types[i + 1..]))
+        .unwrap_or_default()
+        .iter()
+        .map(|t| syn::parse_str::<Type>(t).unwrap())
+        .collect()
+}
+
+/// Helper function to extract the enum-level prefix from the [`Attribute`], aka `#[value_prefix = <literal>]`
+///
+/// # Output
+///
+/// [`None`] if the attribute is not present, or [`Err`] if the prefix is not a
+/// byte string or string literal
+fn get_value_prefix(attrs: &[Attribute]) -> Result<Option<syn::Lit>, Error> {
+    for attr in attrs {
+        if !attr.path.is_ident("value_prefix") { continue; }
+        return match attr.parse_meta() {
+            Ok(Meta::NameValue(MetaNameValue { lit: lit @ (syn::Lit::ByteStr(_) | syn::Lit::Str(_)), .. })) => Ok(Some(lit)),
+            _ => Err(Error::InvalidValuePrefix),
+        };
+    }
+    Ok(None)
+}
+
+/// Helper function to prepend the prefix from [`get_value_prefix`] to a value
+///
+/// Both must be the same kind of literal, and are concatenated into a single literal
+fn apply_value_prefix(prefix: &syn::Lit, variant: &syn::Variant, value: proc_macro2::TokenStream) -> Result<proc_macro2::TokenStream, Error> {
+    match (prefix, syn::parse2::<syn::Lit>(value)) {
+        (syn::Lit::ByteStr(prefix), Ok(syn::Lit::ByteStr(value))) => {
+            let bytes = [prefix.value(), value.value()].concat();
+            Ok(syn::LitByteStr::new(&bytes, value.span()).into_token_stream())
+        },
+        (syn::Lit::Str(prefix), Ok(syn::Lit::Str(value))) => {
+            let string = prefix.value() + &value.value();
+            Ok(syn::LitStr::new(&string, value.span()).into_token_stream())
+        },
+        _ => Err(Error::MismatchedValuePrefix(variant.ident.to_string())),
+    }
+}
+
+/// Helper function to group the variants by their own armtype, aka `#[armtype(<type>)]`
+/// on a variant, in order of first appearance
+///
+/// Variants without an armtype, or with the same armtype as the enum, are not included
+///
+/// # Output
+///
+/// [`Vec`] of each armtype with the identifiers of its variants, or [`Err`] if any
+/// armtype is invalid
+fn get_arm_types<'a>(
+    variants: &'a syn::punctuated::Punctuated<syn::Variant, syn::token::Comma>,
+    type_name_raw: &Type,
+) -> Result<Vec<(Type, Vec<&'a syn::Ident>)>, Error> {
+    let type_string = |typ: &Type| {
+        let mut typ = typ.clone();
+        set_static_lifetimes(&mut typ);
+        typ.to_token_stream().to_string()
+    };
+    let mut arm_types: Vec<(Type, Vec<&syn::Ident>)> = Vec::new();
+    for variant in variants {
+        let arm_type = match get_type(&variant.attrs)? {
+            Some(arm_type) if type_string(&arm_type) != type_string(type_name_raw) => arm_type,
+            _ => continue,
+        };
+        match arm_types.iter_mut().find(|(typ, _)| type_string(typ) == type_string(&arm_type)) {
+            Some((_, names)) => names.push(&variant.ident),
+            None => arm_types.push((arm_type, vec![&variant.ident])),
         }
-    });
-    // ------------------------------------------------
-    // return
-    // ------------------------------------------------
-    let expanded = quote! {
-        #[automatically_derived]
-        #[doc = concat!(" [`ConstEach`] implementation for [`", stringify!(#enum_name), "`]")]
-        impl #enum_name {
-            pub fn value<T: 'static>(&self) -> Option<&'static T> {
-                match self {
-                    #( #variant_code )*
-                    _ => None,
-                }
-            }
+    }
+    Ok(arm_types)
+}
+
+/// Helper function to find the default variant, aka `#[default]`
+///
+/// # Output
+///
+/// [`None`] if there is no default variant, or [`Err`] if there are multiple
+/// or the variant is not a unit variant
+fn get_default(variants: &syn::punctuated::Punctuated<syn::Variant, syn::token::Comma>) -> Result<Option<&syn::Ident>, Error> {
+    let mut default: Option<&syn::Ident> = None;
+    for variant in variants.iter().filter(|variant| variant.attrs.iter().any(|attr| attr.path.is_ident("default"))) {
+        if let Some(first) = default {
+            return Err(Error::MultipleDefault(first.to_string(), variant.ident.to_string()));
         }
+        if !matches!(variant.fields, syn::Fields::Unit) {
+            return Err(Error::NonUnitDefault(variant.ident.to_string()));
+        }
+        default = Some(&variant.ident);
+    }
+    Ok(default)
+}
+
+/// Helper function to get the error for a literal value which does not match the raw
+/// armtype [`Type`], see [`get_mismatched_lit_kind`]
+///
+/// # Output
+///
+/// [`None`] if the value matches, or cannot be checked. Otherwise [`Some`] containing
+/// the error, spanned on the value and naming the variant
+fn get_mismatched_lit_error(variant: &syn::Variant, typ: &Type, value: &proc_macro2::TokenStream) -> Option<syn::Error> {
+    let kind = get_mismatched_lit_kind(typ, value)?;
+    let e = Error::MismatchedLitKind(variant.ident.to_string(), kind.into(), typ.to_token_stream().to_string());
+    Some(syn::Error::new_spanned(value, e))
+}
+
+/// Helper function to get the error for a byte string value whose length does not
+/// match a byte array armtype [`Type`], e.g. `b"\x01"` for `&[u8; 2]`
+///
+/// # Output
+///
+/// [`None`] if the length matches, or the armtype is not a byte array. Otherwise
+/// [`Some`] containing the error, spanned on the value and naming the variant
+fn get_mismatched_byte_length_error(variant: &syn::Variant, typ: &Type, value: &proc_macro2::TokenStream) -> Option<syn::Error> {
+    let len = get_byte_array_len(typ)?;
+    let value = match syn::parse2::<syn::Lit>(value.clone()) {
+        Ok(syn::Lit::ByteStr(value)) if value.value().len() != len => value,
+        _ => return None,
     };
-    TokenStream::from(expanded)
+    let e = Error::MismatchedByteLength(variant.ident.to_string(), value.value().len(), typ.to_token_stream().to_string());
+    Some(syn::Error::new_spanned(value, e))
 }
 
-/// Helper function to extract the value from a [`MetaNameValue`], aka `#[value = <value>]`
+/// Helper function to check if a literal value matches the raw armtype [`Type`]
 ///
-/// # Input
+/// Only integer, float, `&str`, `&[u8]`, `char` and `bool` armtypes are checked,
+/// and only literal values (including negative literals and ranges)
 ///
-/// ```text
-/// #[value = <value>]
-/// ```
+/// # Output
+///
+/// [`None`] if the value matches, or cannot be checked. Otherwise [`Some`] containing
+/// the kind of the literal, e.g. `"a string literal"`
+fn get_mismatched_lit_kind(typ: &Type, value: &proc_macro2::TokenStream) -> Option<&'static str> {
+    let lit = get_lit(value)?;
+    let typ_string = get_primitive(typ).unwrap_or_else(|| typ.to_token_stream().to_string());
+    let suffix_matches = |suffix: &str| suffix.is_empty() || suffix == typ_string;
+    let (typ_deref, deref) = split_deref_type(typ.clone());
+    let matches = match &lit {
+        _ if is_integer_type(typ) => match &lit {
+            syn::Lit::Int(lit) => suffix_matches(lit.suffix()),
+            syn::Lit::Byte(_) => typ_string == "u8",
+            _ => false,
+        },
+        _ if is_float_type(typ) => matches!(&lit, syn::Lit::Float(lit) if suffix_matches(lit.suffix())),
+        _ if deref && is_str_type(&typ_deref) => matches!(lit, syn::Lit::Str(_)),
+        _ if deref && is_byte_slice_type(&typ_deref) => matches!(lit, syn::Lit::ByteStr(_)),
+        _ if get_byte_array_len(typ).is_some() => matches!(lit, syn::Lit::ByteStr(_)),
+        _ if typ_string == "char" => matches!(lit, syn::Lit::Char(_)),
+        _ if typ_string == "bool" => matches!(lit, syn::Lit::Bool(_)),
+        _ => true,
+    };
+    match matches {
+        true => None,
+        false => Some(get_lit_kind(&lit)),
+    }
+}
+
+/// Helper function to get the error for a literal value which can not be cast to the
+/// armtype [`Type`] of a [`ConstEach`] arm, see [`get_uncastable_lit_kind`]
 ///
 /// # Output
 ///
-/// [`TokenStream`] containing the value `<value>`, or [`Err`] if the attribute is not present / invalid
-fn get_val(name: String, attrs: &[Attribute]) -> Result<proc_macro2::TokenStream, Error> {
-    for attr in attrs {
-        if !attr.path.is_ident("value") { continue; }
-        match attr.parse_meta() {
-            Ok(meta) => match meta {
-                Meta::NameValue(MetaNameValue { lit, .. }) => return Ok(lit.into_token_stream()),
-                Meta::List(list) => {
-                    let tokens = list.nested.iter().map(|nested_meta| {
-                        match nested_meta {
-                            syn::NestedMeta::Lit(lit) => lit.to_token_stream(),
-                            syn::NestedMeta::Meta(meta) => meta.to_token_stream(),
-                        }
-                    });
-                    return Ok(quote! { #( #tokens )* });
-                }
-                Meta::Path(_) => return Ok(meta.into_token_stream())
-            },
-            Err(_) => {
-                return Err(Error::NonLiteralValue);
-                /*
-                // Maybe for future:
-                // --------------------------------------------------
-                let elems = attr
-                    .to_token_stream()
-                    .to_string();
-                // println!("elems: {}", elems);
-                let mut elems = elems
-                    .trim()
-                    .trim_start_matches("#[")
-                    .rsplit_once("]")
-                    .unwrap()
-                    .0
-                    .split("=")
-                    .collect::<Vec<_>>();
-                // println!("elems: {:?}", elems);
-                elems.remove(0);
-                // println!("elems: {:?}", elems);
-                return Ok(elems
-                    .join("=")
-                    .trim()
-                    .parse::<proc_macro2::TokenStream>()?);
-                // --------------------------------------------------
-                */
-            },
+/// [`None`] if the value can be cast, or cannot be checked. Otherwise [`Some`]
+/// containing the error, spanned on the value and naming the variant
+fn get_uncastable_lit_error(variant: &syn::Variant, typ: &Type, value: &proc_macro2::TokenStream) -> Option<syn::Error> {
+    let kind = get_uncastable_lit_kind(typ, value)?;
+    let e = Error::MismatchedLitKind(variant.ident.to_string(), kind.into(), typ.to_token_stream().to_string());
+    Some(syn::Error::new_spanned(value, e))
+}
+
+/// Helper function to check if a literal value can be cast to the [`Type`] of an arm of
+/// [`ConstEach`] using `as`, e.g. `1` to `f32`. See [`get_mismatched_lit_kind`]
+///
+/// # Output
+///
+/// [`None`] if the value can be cast, or cannot be checked. Otherwise [`Some`] containing
+/// the kind of the literal, e.g. `"a string literal"`
+fn get_uncastable_lit_kind(typ: &Type, value: &proc_macro2::TokenStream) -> Option<&'static str> {
+    let lit = get_lit(value)?;
+    let castable = match &lit {
+        syn::Lit::Str(_) | syn::Lit::ByteStr(_) => get_mismatched_lit_kind(typ, value).is_none(),
+        _ if is_integer_type(typ) => matches!(lit, syn::Lit::Int(_) | syn::Lit::Float(_) | syn::Lit::Byte(_) | syn::Lit::Char(_) | syn::Lit::Bool(_)),
+        _ if is_float_type(typ) => matches!(lit, syn::Lit::Int(_) | syn::Lit::Float(_)),
+        _ if get_primitive(typ).as_deref() == Some("char") => matches!(lit, syn::Lit::Int(_) | syn::Lit::Byte(_) | syn::Lit::Char(_)),
+        _ => get_mismatched_lit_kind(typ, value).is_none(),
+    };
+    match castable {
+        true => None,
+        false => Some(get_lit_kind(&lit)),
+    }
+}
+
+/// Helper function to get the literal of a value, including negative literals
+/// and the start of ranges
+///
+/// # Output
+///
+/// [`None`] if the value is not a literal, otherwise [`Some<syn::Lit>`]
+fn get_lit(value: &proc_macro2::TokenStream) -> Option<syn::Lit> {
+    let value = get_range_start(value).unwrap_or_else(|| value.clone());
+    match syn::parse2::<syn::Expr>(value).ok()? {
+        syn::Expr::Lit(syn::ExprLit { lit, .. }) => Some(lit),
+        syn::Expr::Unary(syn::ExprUnary { op: syn::UnOp::Neg(_), expr, .. }) => match *expr {
+            syn::Expr::Lit(syn::ExprLit { lit, .. }) => Some(lit),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Helper function to describe the kind of a literal in errors, e.g. `"a string literal"`
+fn get_lit_kind(lit: &syn::Lit) -> &'static str {
+    match lit {
+        syn::Lit::Str(_) => "a string literal",
+        syn::Lit::ByteStr(_) => "a byte string literal",
+        syn::Lit::Byte(_) => "a byte literal",
+        syn::Lit::Char(_) => "a char literal",
+        syn::Lit::Int(_) => "an integer literal",
+        syn::Lit::Float(_) => "a float literal",
+        syn::Lit::Bool(_) => "a bool literal",
+        syn::Lit::Verbatim(_) => "a literal",
+    }
+}
+
+/// Helper function to get the error for a computed value of a [`ConstEach`] arm without
+/// a type, e.g. `#[value(vec![1, 2])]`, which can not be promoted to a `&'static` reference
+///
+/// # Output
+///
+/// [`None`] if the value is not computed. Otherwise [`Some`] containing the error,
+/// spanned on the value and naming the variant
+fn get_untyped_computed_error(variant: &syn::Variant, value: &proc_macro2::TokenStream, name: &str) -> Option<syn::Error> {
+    if !is_computed_value(value) { return None; }
+    let e = Error::MissingArmType(format!("for the computed value of `{}`, since it is not `'static`", variant.ident), name.into());
+    Some(syn::Error::new_spanned(value, e))
+}
+
+/// Helper function to check if a value is computed at runtime for [`ConstEach`], aka
+/// it is not a literal (see [`is_lit_value`]), path, or macro call (e.g. `concat!`)
+///
+/// Macros which construct owned values, aka `vec!` and `format!`, are computed
+fn is_computed_value(value: &proc_macro2::TokenStream) -> bool {
+    match syn::parse2::<syn::Expr>(value.clone()) {
+        _ if is_lit_value(value) => false,
+        Ok(syn::Expr::Macro(syn::ExprMacro { mac, .. })) => mac.path.is_ident("vec") || mac.path.is_ident("format"),
+        Ok(syn::Expr::Path(_)) => false,
+        _ => true,
+    }
+}
+
+/// Helper function to check if a value is the catch-all wildcard, aka `#[value(_)]`
+fn is_wildcard_value(value: &proc_macro2::TokenStream) -> bool {
+    value.to_string() == "_"
+}
+
+/// Helper function to find the catch-all variant, aka `#[value(_)] Other(T)`
+///
+/// # Output
+///
+/// [`None`] if there is no catch-all variant, or [`Err`] if there are multiple
+/// or the variant does not have exactly one unnamed field
+///
+/// Otherwise a tuple:
+///
+/// * 0 - The index of the catch-all variant
+/// * 1 - The name of the catch-all variant
+fn get_catch_all(
+    variants: &syn::punctuated::Punctuated<syn::Variant, syn::token::Comma>,
+    values: &[proc_macro2::TokenStream],
+) -> Result<Option<(usize, syn::Ident)>, Error> {
+    let mut catch_all: Option<(usize, syn::Ident)> = None;
+    for (i, (variant, value)) in variants.iter().zip(values).enumerate() {
+        if !is_wildcard_value(value) { continue; }
+        if let Some((_, first)) = &catch_all {
+            return Err(Error::MultipleCatchAll(first.to_string(), variant.ident.to_string()));
+        }
+        match &variant.fields {
+            syn::Fields::Unnamed(syn::FieldsUnnamed { unnamed, .. }) if unnamed.len() == 1 => (),
+            _ => return Err(Error::InvalidCatchAll(variant.ident.to_string())),
         }
+        catch_all = Some((i, variant.ident.clone()));
     }
-    Err(Error::MissingValue(name))
+    Ok(catch_all)
 }
 
 /// Helper function to extract the type from the [`Attribute`], aka `#[armtype(<type>)]`
 /// 
-/// Will indicate whether or not the type should be dereferenced or not. Useful
-/// for the [`Const`] macro
+/// Will return the raw [`Type`]. Useful for the [`Const`] and the [`ConstEach`]
+/// macros
 ///
 /// # Input
 ///
@@ -572,67 +3034,628 @@ fn get_val(name: String, attrs: &[Attribute]) -> Result<proc_macro2::TokenStream
 ///
 /// # Output
 ///
-/// [`None`] if the attribute is not present / invalid
-/// 
-/// Otherwise a tuple:
+/// [`None`] if the attribute is not present, or [`Err`] if the attribute is invalid
 /// 
-/// * 0 - [`Type`] containing the type `<type>` (already de-referenced)
-/// * 1 - An additional flag that indicates if the type has been de-referenced
-fn get_deref_type(attrs: &[Attribute]) -> Option<(Type, bool)> {
+/// Otherwise [`Some<Type>`] containing the type `<type>`
+fn get_type(attrs: &[Attribute]) -> Result<Option<Type>, Error> {
     for attr in attrs {
-        if !attr.path.is_ident("armtype") { continue; }
-        let tokens = match attr.parse_args::<proc_macro2::TokenStream>() {
-            Ok(tokens) => tokens,
-            Err(_) => return None,
-        };
-        let deref = tokens
-            .to_string()
-            .trim()
-            .starts_with('&');
-        let tokens = match deref {
-            true => {
-                let mut tokens = tokens.into_iter();
-                let _ = tokens.next();
-                tokens.collect::<proc_macro2::TokenStream>()
-            }
-            false => tokens,
+        if !is_armtype_attr(attr) { continue; }
+        return match parse_armtype_args(attr) {
+            Ok((typ, _)) => Ok(Some(typ)),
+            Err(e) => Err(Error::InvalidArmType(e.to_string())),
         };
-        return match syn::parse2::<Type>(tokens).ok() {
-            Some(type_name) => Some((type_name, deref)),
-            None => None
-        }
     }
-    None
+    Ok(None)
 }
 
-/// Helper function to extract the type from the [`Attribute`], aka `#[armtype(<type>)]`
+/// Helper function to parse the arguments of `#[armtype(<type>, <modifiers>...)]`
 /// 
-/// Will return the raw [`Type`]. Useful for the [`Const`] and the [`ConstEach`]
-/// macros
+/// Modifiers are either `<name>` or `<name> = <expr>`, where `<name>` may be a
+/// keyword (e.g. `return`). A trailing comma is allowed, e.g. `#[armtype(u8,)]`
+/// 
+/// Unsized types, aka `str` and slices (e.g. `[u8]`), can only be values
+/// by reference, so they are parsed as if `&` was written, e.g. `&str`
+fn parse_armtype_args(attr: &Attribute) -> syn::Result<(Type, ArmTypeOptions)> {
+    attr.parse_args_with(|input: syn::parse::ParseStream| {
+        let typ = match input.parse::<Type>()? {
+            typ @ Type::Slice(_) => syn::parse_quote! { &#typ },
+            typ if is_str_type(&typ) => syn::parse_quote! { &#typ },
+            typ => typ,
+        };
+        let mut options = ArmTypeOptions::default();
+        while !input.is_empty() {
+            input.parse::<syn::Token![,]>()?;
+            if input.is_empty() { break; }
+            let modifier = syn::ext::IdentExt::parse_any(input)?;
+            match input.peek(syn::Token![=]) {
+                true => {
+                    input.parse::<syn::Token![=]>()?;
+                    options.values.push((modifier, input.parse::<syn::Expr>()?));
+                },
+                false => options.modifiers.push(modifier),
+            }
+        }
+        Ok((typ, options))
+    })
+}
+
+/// Helper function to extract an expression from a `#[value(<expr>)]` attribute
+///
+/// Since `rustc` only accepts literals (and macro calls) in `#[value = ...]`,
+/// other expressions must be written in the list form
 ///
 /// # Input
 ///
 /// ```text
-/// #[armtype(<type>)]
+/// #[value(<expr>)]
+/// #[value(<expr>, <expr>, ...)]
+/// #[value = <macro>!(...)]
 /// ```
 ///
 /// # Output
 ///
-/// [`None`] if the attribute is not present / invalid
-/// 
-/// Otherwise [`Some<Type>`] containing the type `<type>`
-fn get_type(attrs: &[Attribute]) -> Option<Type> {
-    for attr in attrs {
-        if !attr.path.is_ident("armtype") { continue; }
-        let tokens = match attr.parse_args::<proc_macro2::TokenStream>() {
-            Ok(tokens) => tokens,
-            Err(_) => return None,
+/// [`None`] if the attribute is not a valid expression
+///
+/// Otherwise [`Some<TokenStream>`] containing the expression `<expr>`, or a
+/// slice of the expressions, aka `&[<expr>, <expr>, ...]`
+fn get_expr_val(attr: &Attribute) -> Option<proc_macro2::TokenStream> {
+    let name_value = |input: syn::parse::ParseStream| {
+        input.parse::<syn::Token![=]>()?;
+        input.parse::<syn::Expr>()
+    };
+    let slice = |input: syn::parse::ParseStream| {
+        let elems = syn::punctuated::Punctuated::<syn::Expr, syn::Token![,]>::parse_terminated(input)?;
+        match elems.len() > 1 {
+            true => Ok(syn::parse_quote! { &[ #elems ] }),
+            false => Err(input.error("expected multiple expressions")),
+        }
+    };
+    attr.parse_args::<syn::Expr>()
+        .or_else(|_| syn::parse::Parser::parse2(name_value, attr.tokens.clone()))
+        .or_else(|_| attr.parse_args_with(slice))
+        .ok()
+        .map(|expr: syn::Expr| expr.into_token_stream())
+}
+
+/// Helper function to check if a value can be used directly as a pattern, and
+/// is promoted to a `&'static` reference. This includes literals, negative
+/// literals, ranges, and tuples or array references (e.g. `&[1, 2, 3]`) of these
+fn is_lit_value(value: &proc_macro2::TokenStream) -> bool {
+    match syn::parse2::<syn::Expr>(value.clone()) {
+        Ok(expr) => is_lit_expr(&expr),
+        Err(_) => false,
+    }
+}
+
+/// Helper function to check if an [`Expr`](syn::Expr) is a literal, see [`is_lit_value`]
+fn is_lit_expr(expr: &syn::Expr) -> bool {
+    match expr {
+        syn::Expr::Lit(_) => true,
+        syn::Expr::Range(_) => true,
+        syn::Expr::Unary(syn::ExprUnary { op: syn::UnOp::Neg(_), expr, .. }) => matches!(**expr, syn::Expr::Lit(_)),
+        syn::Expr::Tuple(syn::ExprTuple { elems, .. }) => elems.iter().all(|elem| !matches!(elem, syn::Expr::Range(_)) && is_lit_expr(elem)),
+        syn::Expr::Reference(syn::ExprReference { mutability: None, expr, .. }) => match expr.as_ref() {
+            syn::Expr::Array(syn::ExprArray { elems, .. }) => elems.iter().all(|elem| !matches!(elem, syn::Expr::Range(_)) && is_lit_expr(elem)),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+/// Helper function to get the identifier of the associated constant
+/// storing a non-literal value, see [`is_lit_value`]
+fn value_const_ident(index: usize) -> syn::Ident {
+    quote::format_ident!("__THISENUM_VALUE_{}", index)
+}
+
+/// Helper function to get a `const` block which fails to compile if any two values
+/// are the same, aka `#[thisenum(unique)]`
+///
+/// Skipped variants and ranges are not compared
+///
+/// # Output
+///
+/// The `const _: () = { ... };` block, or [`Err`] if the armtype can not be
+/// compared in a `const` context. Supported are integers, `char`, `bool`, `&str` and `&[u8]`
+fn get_unique_check(
+    enum_name: &syn::Ident,
+    variants: &syn::punctuated::Punctuated<syn::Variant, syn::token::Comma>,
+    values: &[proc_macro2::TokenStream],
+    skips: &[bool],
+    type_name_raw: &Type,
+) -> Result<proc_macro2::TokenStream, Error> {
+    let (type_name, deref) = split_deref_type(type_name_raw.clone());
+    let values_eq = match (deref, &type_name) {
+        (false, typ) if is_integer_type(typ) || matches!(get_primitive(typ).as_deref(), Some("char" | "bool")) => quote! {
+            a == b
+        },
+        (true, typ) if is_str_type(typ) => quote! {
+            let (a, b) = (a.as_bytes(), b.as_bytes());
+            if a.len() != b.len() { return false; }
+            let mut i = 0;
+            while i < a.len() {
+                if a[i] != b[i] { return false; }
+                i += 1;
+            }
+            true
+        },
+        (true, typ) if is_byte_slice_type(typ) => quote! {
+            if a.len() != b.len() { return false; }
+            let mut i = 0;
+            while i < a.len() {
+                if a[i] != b[i] { return false; }
+                i += 1;
+            }
+            true
+        },
+        _ => return Err(Error::InvalidEnumOption(format!(
+            "`unique` requires an integer, `char`, `bool`, `&str` or `&[u8]` armtype, found `{}`",
+            type_name_raw.to_token_stream(),
+        ))),
+    };
+    let (checked_values, messages): (Vec<_>, Vec<_>) = variants
+        .iter()
+        .zip(values.iter())
+        .zip(skips.iter())
+        .filter(|((_, value), skip)| !**skip && get_range_start(value).is_none())
+        .map(|((variant, value), _)| (value, format!("`{}::{}` has the same value as a previous variant", enum_name, variant.ident)))
+        .unzip();
+    let indices = 0..checked_values.len();
+    let len = checked_values.len();
+    Ok(quote! {
+        const _: () = {
+            const VALUES: [#type_name_raw; #len] = [ #( #checked_values ),* ];
+            const fn values_eq(a: #type_name_raw, b: #type_name_raw) -> bool {
+                #values_eq
+            }
+            const fn is_repeated(index: usize) -> bool {
+                let mut i = 0;
+                while i < index {
+                    if values_eq(VALUES[i], VALUES[index]) { return true; }
+                    i += 1;
+                }
+                false
+            }
+            #( if is_repeated(#indices) { panic!(#messages); } )*
         };
-        return syn::parse2::<Type>(
-            tokens
-            .into_iter()
-            .collect::<proc_macro2::TokenStream>()
-        ).ok()
+    })
+}
+
+/// Helper function to get the start of a range value, aka `<start>..=<end>`
+///
+/// # Output
+///
+/// [`None`] if the value is not a range
+///
+/// Otherwise [`Some<TokenStream>`] containing `<start>`
+fn get_range_start(value: &proc_macro2::TokenStream) -> Option<proc_macro2::TokenStream> {
+    match syn::parse2::<syn::Expr>(value.clone()).ok()? {
+        syn::Expr::Range(syn::ExprRange { from: Some(from), .. }) => Some(from.into_token_stream()),
+        _ => None,
+    }
+}
+
+/// Helper function to infer the type of a literal value, aka `#[value = <literal>]`
+///
+/// Integer and float literals use their suffix if present, otherwise the
+/// defaults of `i32` and `f64`. String and byte string literals are inferred
+/// as `&str` and `&[u8]` respectively
+///
+/// # Output
+///
+/// [`None`] if the value is not a literal
+///
+/// Otherwise [`Some<Type>`] containing the inferred type
+fn get_lit_type(value: &proc_macro2::TokenStream) -> Option<Type> {
+    let value = get_range_start(value).unwrap_or_else(|| value.clone());
+    let typ = match syn::parse2::<syn::Lit>(value).ok()? {
+        syn::Lit::Str(_) => quote! { &str },
+        syn::Lit::ByteStr(_) => quote! { &[u8] },
+        syn::Lit::Byte(_) => quote! { u8 },
+        syn::Lit::Char(_) => quote! { char },
+        syn::Lit::Bool(_) => quote! { bool },
+        syn::Lit::Int(lit) => match lit.suffix() {
+            "" => quote! { i32 },
+            suffix => suffix.parse().ok()?,
+        },
+        syn::Lit::Float(lit) => match lit.suffix() {
+            "" => quote! { f64 },
+            suffix => suffix.parse().ok()?,
+        },
+        syn::Lit::Verbatim(_) => return None,
+    };
+    syn::parse2::<Type>(typ).ok()
+}
+
+/// Helper function to infer the type shared by all values of an enum
+///
+/// The type is inferred from the first value, and all other values
+/// are checked to be the same kind of literal. Unsuffixed integer and float
+/// literals are compatible with any integer or float type respectively
+///
+/// # Output
+///
+/// [`Ok<Type>`] containing the inferred type, or [`Err`] naming
+/// the first variant whose value does not match
+fn infer_shared_type(
+    name: &str,
+    variants: &syn::punctuated::Punctuated<syn::Variant, syn::token::Comma>,
+    values: &[proc_macro2::TokenStream],
+) -> Result<Type, Error> {
+    let typ = match values.first().and_then(get_lit_type) {
+        Some(typ) => typ,
+        None => return Err(Error::MissingArmType("applied to enum".into(), name.into())),
+    };
+    let typ_string = typ.to_token_stream().to_string();
+    for (variant, value) in variants.iter().zip(values) {
+        let value = get_range_start(value).unwrap_or_else(|| value.clone());
+        let matches = match syn::parse2::<syn::Lit>(value.clone()) {
+            Ok(syn::Lit::Int(lit)) if lit.suffix().is_empty() => is_integer_type(&typ),
+            Ok(syn::Lit::Float(lit)) if lit.suffix().is_empty() => matches!(typ_string.as_str(), "f32" | "f64"),
+            _ => get_lit_type(&value).is_some_and(|t| t.to_token_stream().to_string() == typ_string),
+        };
+        if !matches { return Err(Error::MismatchedValueType(variant.ident.to_string(), typ_string)); }
+    }
+    Ok(typ)
+}
+
+/// Helper function to split a reference [`Type`] into the referenced type
+/// and a flag indicating if it was de-referenced, aka `&[u8]` to `([u8], true)`
+///
+/// See [`parse_armtype`] for the attribute equivalent
+fn split_deref_type(typ: Type) -> (Type, bool) {
+    match typ {
+        Type::Reference(syn::TypeReference { elem, .. }) => (*elem, true),
+        typ => (typ, false),
+    }
+}
+
+/// Helper function to check if a [`Type`] is a byte slice, aka `[u8]`
+fn is_byte_slice_type(typ: &Type) -> bool {
+    match typ {
+        Type::Slice(syn::TypeSlice { elem, .. }) => matches!(
+            elem.as_ref(),
+            typ if get_primitive(typ).as_deref() == Some("u8")
+        ),
+        _ => false,
+    }
+}
+
+/// Helper function to get the length of a byte array reference [`Type`], aka `&[u8; N]`
+///
+/// # Output
+///
+/// [`None`] if the type is not a reference to a byte array with a literal length,
+/// otherwise [`Some`] containing `N`
+fn get_byte_array_len(typ: &Type) -> Option<usize> {
+    let typ = match split_deref_type(typ.clone()) {
+        (typ, true) => typ,
+        (_, false) => return None,
+    };
+    match typ {
+        Type::Array(syn::TypeArray { elem, len: syn::Expr::Lit(syn::ExprLit { lit: syn::Lit::Int(len), .. }), .. }) => match elem.as_ref() {
+            typ if get_primitive(typ).as_deref() == Some("u8") => len.base10_parse().ok(),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Helper function to set all elided lifetimes of references within a [`Type`] to `'static`,
+/// aka `[&str]` to `[&'static str]`
+fn set_static_lifetimes(typ: &mut Type) {
+    match typ {
+        Type::Reference(syn::TypeReference { lifetime, elem, .. }) => {
+            if lifetime.is_none() {
+                *lifetime = Some(syn::Lifetime::new("'static", proc_macro2::Span::call_site()));
+            }
+            set_static_lifetimes(elem);
+        },
+        Type::Slice(syn::TypeSlice { elem, .. }) => set_static_lifetimes(elem),
+        Type::Array(syn::TypeArray { elem, .. }) => set_static_lifetimes(elem),
+        Type::Paren(syn::TypeParen { elem, .. }) => set_static_lifetimes(elem),
+        Type::Group(syn::TypeGroup { elem, .. }) => set_static_lifetimes(elem),
+        Type::Tuple(syn::TypeTuple { elems, .. }) => elems.iter_mut().for_each(set_static_lifetimes),
+        _ => (),
+    }
+}
+
+/// Helper function to check if a [`Type`] is a slice of strings, aka `[&str]`
+fn is_str_slice_type(typ: &Type) -> bool {
+    match typ {
+        Type::Slice(syn::TypeSlice { elem, .. }) => matches!(
+            elem.as_ref(),
+            Type::Reference(syn::TypeReference { elem, .. }) if is_str_type(elem)
+        ),
+        _ => false,
+    }
+}
+
+/// Helper function to sort byte string values, aka `b"..."`, for a binary search
+///
+/// Values with the same bytes are removed, keeping the first, to
+/// be consistent with the first matching arm of a `match`
+///
+/// # Output
+///
+/// [`None`] if any of the values is not a byte string literal
+///
+/// Otherwise a tuple:
+///
+/// * 0 - [`Vec<proc_macro2::Literal>`] containing the sorted byte strings
+/// * 1 - [`Vec<usize>`] containing the index of each byte string in the input
+fn get_byte_table(values: &[proc_macro2::TokenStream]) -> Option<(Vec<proc_macro2::Literal>, Vec<usize>)> {
+    let mut table = values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| match syn::parse2::<syn::Lit>(value.clone()) {
+            Ok(syn::Lit::ByteStr(lit)) => Some((lit.value(), i)),
+            _ => None,
+        })
+        .collect::<Option<Vec<_>>>()?;
+    table.sort_by(|a, b| a.0.cmp(&b.0));
+    table.dedup_by(|a, b| a.0 == b.0);
+    Some(table
+        .into_iter()
+        .map(|(bytes, i)| (proc_macro2::Literal::byte_string(&bytes), i))
+        .unzip())
+}
+
+/// Helper function to check if a [`Type`] is a string slice, aka `str`
+fn is_str_type(typ: &Type) -> bool {
+    get_primitive(typ).as_deref() == Some("str")
+}
+
+/// Helper function to check if a [`Type`] is a primitive float, aka `f32` or `f64`
+fn is_float_type(typ: &Type) -> bool {
+    matches!(get_primitive(typ).as_deref(), Some("f32" | "f64"))
+}
+
+/// Helper function to check if a [`Type`] is a primitive integer, e.g. `u8` or `i64`
+fn is_integer_type(typ: &Type) -> bool {
+    matches!(
+        get_primitive(typ).as_deref(),
+        Some("u8" | "u16" | "u32" | "u64" | "u128" | "usize" |
+             "i8" | "i16" | "i32" | "i64" | "i128" | "isize")
+    )
+}
+
+/// Helper function to get the name of a primitive [`Type`], e.g. `u8`
+///
+/// Fully-qualified paths to primitives, aka `::core::primitive::u8` or
+/// `std::primitive::u8`, are treated the same as the primitive itself
+///
+/// # Output
+///
+/// [`Some`] containing the identifier (e.g. `u8`, or `MyType`), or the primitive of a
+/// fully-qualified path. [`None`] for any other type
+fn get_primitive(typ: &Type) -> Option<String> {
+    let path = match typ {
+        Type::Path(syn::TypePath { qself: None, path }) => path,
+        _ => return None,
+    };
+    if let Some(ident) = path.get_ident() {
+        return Some(ident.to_string());
+    }
+    let segments = path.segments.iter().map(|segment| match segment.arguments {
+        syn::PathArguments::None => Some(segment.ident.to_string()),
+        _ => None,
+    }).collect::<Option<Vec<_>>>()?;
+    match segments.as_slice() {
+        [krate, module, primitive] if (krate == "core" || krate == "std") && module == "primitive" => Some(primitive.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_armtype_with_modifiers() {
+        let attrs: Vec<Attribute> = vec![syn::parse_quote!(#[armtype(&[u8], hex, map)])];
+        let (typ, deref, options) = parse_armtype(&attrs).unwrap().unwrap();
+        assert_eq!(typ.to_token_stream().to_string(), "[u8]");
+        assert!(deref);
+        assert!(options.has("hex"));
+        assert!(options.has("map"));
+        assert!(!options.has("no_inline"));
+        assert_eq!(options.unknown(&["hex"]).unwrap(), "map");
+        assert!(options.unknown(&["hex", "map"]).is_none());
+    }
+
+    #[test]
+    fn parse_armtype_without_modifiers() {
+        let attrs: Vec<Attribute> = vec![syn::parse_quote!(#[armtype(u8,)])];
+        let (typ, deref, options) = parse_armtype(&attrs).unwrap().unwrap();
+        assert_eq!(typ.to_token_stream().to_string(), "u8");
+        assert!(!deref);
+        assert!(options.modifiers.is_empty());
+    }
+
+    #[test]
+    fn parse_armtype_with_values() {
+        let attrs: Vec<Attribute> = vec![syn::parse_quote!(#[armtype(u8, no_inline, auto = increment(3))])];
+        let (_, _, options) = parse_armtype(&attrs).unwrap().unwrap();
+        assert!(options.has("no_inline"));
+        assert!(!options.has("auto"));
+        assert_eq!(get_auto_start(options.get("auto").unwrap()).unwrap(), 3);
+        assert!(options.unknown(&["no_inline", "auto"]).is_none());
+        assert_eq!(options.unknown(&["no_inline"]).unwrap(), "auto");
+    }
+
+    #[test]
+    fn parse_armtype_missing_or_invalid() {
+        let attrs: Vec<Attribute> = vec![syn::parse_quote!(#[value = 1])];
+        assert!(parse_armtype(&attrs).unwrap().is_none());
+        let attrs: Vec<Attribute> = vec![syn::parse_quote!(#[armtype(u8, 1)])];
+        assert!(parse_armtype(&attrs).is_err());
+    }
+
+    #[test]
+    fn parse_armtype_unsized() {
+        let attrs: Vec<Attribute> = vec![syn::parse_quote!(#[armtype(str)])];
+        let (typ, deref, _) = parse_armtype(&attrs).unwrap().unwrap();
+        assert_eq!(typ.to_token_stream().to_string(), "str");
+        assert!(deref);
+        let attrs: Vec<Attribute> = vec![syn::parse_quote!(#[armtype([u8], be)])];
+        assert_eq!(get_type(&attrs).unwrap().unwrap().to_token_stream().to_string(), "& [u8]");
+    }
+
+    #[test]
+    fn snake_case() {
+        assert_eq!(to_snake_case("Key"), "key");
+        assert_eq!(to_snake_case("NotFound"), "not_found");
+        assert_eq!(to_snake_case("HTTPCode"), "http_code");
+        assert_eq!(to_snake_case("Utf8Error"), "utf8_error");
+        assert_eq!(to_snake_case("A"), "a");
+    }
+
+    #[test]
+    fn rename_all() {
+        let ident: syn::Ident = syn::parse_quote!(FooBar);
+        assert_eq!(rename_ident(&ident, None), "FooBar");
+        assert_eq!(rename_ident(&ident, Some("snake_case")), "foo_bar");
+        assert_eq!(rename_ident(&ident, Some("kebab-case")), "foo-bar");
+        assert_eq!(rename_ident(&ident, Some("SCREAMING_SNAKE_CASE")), "FOO_BAR");
+        assert_eq!(rename_ident(&ident, Some("lowercase")), "foobar");
+        assert_eq!(rename_ident(&ident, Some("UPPERCASE")), "FOOBAR");
+    }
+
+    #[test]
+    fn primitive_paths() {
+        let primitive = |typ: Type| get_primitive(&typ);
+        assert_eq!(primitive(syn::parse_quote!(u8)).as_deref(), Some("u8"));
+        assert_eq!(primitive(syn::parse_quote!(::core::primitive::u8)).as_deref(), Some("u8"));
+        assert_eq!(primitive(syn::parse_quote!(std::primitive::str)).as_deref(), Some("str"));
+        assert!(primitive(syn::parse_quote!(other::primitive::u8)).is_none());
+        assert!(primitive(syn::parse_quote!(&u8)).is_none());
+        assert!(is_integer_type(&syn::parse_quote!(::std::primitive::i64)));
+    }
+
+    #[test]
+    fn mismatched_lit_error() {
+        let variant: syn::Variant = syn::parse_quote!(A);
+        let error = |typ: Type, value: proc_macro2::TokenStream| get_mismatched_lit_error(&variant, &typ, &value).map(|e| e.to_string());
+        assert_eq!(
+            error(syn::parse_quote!(u8), quote! { "oops" }).as_deref(),
+            Some("Value of variant `A` is a string literal, which does not match the armtype `u8`"),
+        );
+        assert_eq!(
+            error(syn::parse_quote!(&str), quote! { 1 }).as_deref(),
+            Some("Value of variant `A` is an integer literal, which does not match the armtype `& str`"),
+        );
+        assert!(error(syn::parse_quote!(&[u8]), quote! { "oops" }).is_some());
+        assert!(error(syn::parse_quote!(f32), quote! { 1 }).is_some());
+        assert!(error(syn::parse_quote!(u8), quote! { 1u16 }).is_some());
+        assert!(error(syn::parse_quote!(u8), quote! { 1u8 }).is_none());
+        assert!(error(syn::parse_quote!(u8), quote! { compute(1) }).is_none());
+    }
+
+    #[test]
+    fn mismatched_byte_length_error() {
+        let variant: syn::Variant = syn::parse_quote!(A);
+        let typ: Type = syn::parse_quote!(&[u8; 2]);
+        let error = |value: proc_macro2::TokenStream| get_mismatched_byte_length_error(&variant, &typ, &value).map(|e| e.to_string());
+        assert_eq!(
+            error(quote! { b"\x01" }).as_deref(),
+            Some("Value of variant `A` is 1 bytes long, which does not match the armtype `& [u8 ; 2]`"),
+        );
+        assert_eq!(
+            error(quote! { b"\x01\x02\x03" }).as_deref(),
+            Some("Value of variant `A` is 3 bytes long, which does not match the armtype `& [u8 ; 2]`"),
+        );
+        assert!(error(quote! { b"\x01\x02" }).is_none());
+        assert!(get_mismatched_byte_length_error(&variant, &syn::parse_quote!(&[u8]), &quote! { b"\x01" }).is_none());
+    }
+
+    #[test]
+    fn uncastable_lit_error() {
+        let variant: syn::Variant = syn::parse_quote!(A);
+        let error = |typ: Type, value: proc_macro2::TokenStream| get_uncastable_lit_error(&variant, &typ, &value).map(|e| e.to_string());
+        assert_eq!(
+            error(syn::parse_quote!(u8), quote! { "str" }).as_deref(),
+            Some("Value of variant `A` is a string literal, which does not match the armtype `u8`"),
+        );
+        assert_eq!(
+            error(syn::parse_quote!(f32), quote! { true }).as_deref(),
+            Some("Value of variant `A` is a bool literal, which does not match the armtype `f32`"),
+        );
+        assert!(error(syn::parse_quote!(f32), quote! { 1 }).is_none());
+        assert!(error(syn::parse_quote!(u32), quote! { 'a' }).is_none());
+        assert!(error(syn::parse_quote!(u8), quote! { true }).is_none());
+    }
+
+    #[test]
+    fn int_values() {
+        assert_eq!(get_int_val(&quote! { -170141183460469231731687303715884105728 }), Some(i128::MIN));
+        assert_eq!(get_int_val(&quote! { -170141183460469231731687303715884105729 }), None);
+        assert_eq!(get_int_val(&quote! { 170141183460469231731687303715884105728 }), None);
+        assert_eq!(get_uint_val(&quote! { 340282366920938463463374607431768211455 }), Some(u128::MAX));
+        assert_eq!(get_uint_val(&quote! { -1 }), None);
+        let variant: syn::Variant = syn::parse_quote!(A);
+        let bytes = |value: proc_macro2::TokenStream, bits, signed| int_to_bytes(&variant, value, bits, signed, true).map(|ts| ts.to_string()).ok();
+        assert_eq!(bytes(quote! { 340282366920938463463374607431768211455 }, 128, false).as_deref(), Some(format!("b\"{}\"", "\\xFF".repeat(16)).as_str()));
+        assert_eq!(bytes(quote! { 255 }, 8, false).as_deref(), Some("b\"\\xFF\""));
+        assert!(bytes(quote! { 256 }, 8, false).is_none());
+        assert!(bytes(quote! { -1 }, 8, false).is_none());
+        assert!(bytes(quote! { 128 }, 8, true).is_none());
+        assert!(bytes(quote! { -129 }, 8, true).is_none());
+    }
+
+    #[test]
+    fn auto_vals_overflow() {
+        let auto = |typ: Type, start, variants: syn::punctuated::Punctuated<syn::Variant, syn::token::Comma>| {
+            get_auto_vals("Const", &variants, start, &typ)
+                .map(|values| values.iter().map(|v| v.to_string()).collect::<Vec<_>>())
+                .map_err(|e| e.to_string())
+        };
+        assert_eq!(auto(syn::parse_quote!(u8), 254, syn::parse_quote!(A, B)), Ok(vec!["254".into(), "255".into()]));
+        assert_eq!(
+            auto(syn::parse_quote!(u8), 254, syn::parse_quote!(A, B, C)),
+            Err("Invalid `auto` in #[armtype(...)] attribute: value of variant `C` is out of range for `u8`".into()),
+        );
+        assert_eq!(auto(syn::parse_quote!(i8), -129, syn::parse_quote!(A)).unwrap_err(), "Invalid `auto` in #[armtype(...)] attribute: value of variant `A` is out of range for `i8`");
+        assert_eq!(auto(syn::parse_quote!(u8), 0, syn::parse_quote!(#[value = 255] A, B)).unwrap_err(), "Invalid `auto` in #[armtype(...)] attribute: value of variant `B` is out of range for `u8`");
+        assert!(auto(syn::parse_quote!(u8), 0, syn::parse_quote!(#[value = 255] A, #[value = 0] B, C)).is_ok());
+        assert!(auto(syn::parse_quote!(i128), i128::MAX, syn::parse_quote!(A, B)).is_err());
+        assert!(auto(syn::parse_quote!(u128), 0, syn::parse_quote!(#[value = 340282366920938463463374607431768211455] A, B)).is_err());
     }
-    None
-}
\ No newline at end of file
+
+    #[test]
+    fn unique_check() {
+        let enum_name: syn::Ident = syn::parse_quote!(Repeated);
+        let variants: syn::punctuated::Punctuated<syn::Variant, syn::token::Comma> = syn::parse_quote!(A, B, C);
+        let values = [quote! { 1 }, quote! { 2 }, quote! { 1 }];
+        let check = |typ: Type, skips: &[bool]| get_unique_check(&enum_name, &variants, &values, skips, &typ).map(|ts| ts.to_string());
+        let output = check(syn::parse_quote!(u8), &[false; 3]).unwrap();
+        for variant in ["A", "B", "C"] {
+            assert!(output.contains(&format!("`Repeated::{}` has the same value as a previous variant", variant)));
+        }
+        let output = check(syn::parse_quote!(u8), &[false, false, true]).unwrap();
+        assert!(!output.contains("`Repeated::C`"));
+        assert!(check(syn::parse_quote!(&str), &[false; 3]).is_ok());
+        assert!(check(syn::parse_quote!(f32), &[false; 3]).is_err());
+    }
+
+    #[test]
+    fn untyped_computed_error() {
+        let variant: syn::Variant = syn::parse_quote!(A);
+        let error = |value: proc_macro2::TokenStream| get_untyped_computed_error(&variant, &value, "ConstEach").map(|e| e.to_string());
+        let expected = "Missing #[armtype = ...] attribute for the computed value of `A`, since it is not `'static`, required for `ConstEach`-derived enum";
+        assert_eq!(error(quote! { vec![1, 2, 3] }).as_deref(), Some(expected));
+        assert_eq!(error(quote! { String::from("a") }).as_deref(), Some(expected));
+        assert!(error(quote! { 1 }).is_none());
+        assert!(error(quote! { "a" }).is_none());
+    }
+
+    #[test]
+    fn prelude_singleton() {
+        assert_eq!(vec!["a"].into_iter().counts().get("a"), Some(&1));
+        assert_eq!(vec!["a"].into_iter().positions().get("a"), Some(&vec![0]));
+        assert!(vec!["a"].into_iter().repeated().is_empty());
+        assert!(vec!["a"].into_iter().repeated_idx().is_empty());
+        assert!(Vec::<&str>::new().into_iter().repeated().is_empty());
+        assert!(Vec::<&str>::new().into_iter().repeated_idx().is_empty());
+    }
+}
@@ -12,15 +12,15 @@ pub trait Counts {
 }
 
 /// [Counts] implementation for all iterators
-impl<'a, T, I> Counts for I
+impl<T, I> Counts for I
 where
     T: Eq + Hash,
-    I: Iterator<Item = T> + 'a,
+    I: Iterator<Item = T>,
 {
     type Item = T;
     fn counts(&mut self) -> HashMap<T, usize> {
         let mut counts = HashMap::new();
-        self.for_each(|item| (*counts.entry(item).or_insert(0_usize) += 1_usize));
+        self.for_each(|item| *counts.entry(item).or_insert(0_usize) += 1_usize);
         counts
     }
 }
@@ -87,6 +87,7 @@ where
 }
 
 /// [Unique] trait
+#[allow(dead_code)]
 pub trait Unique<T> {
     fn _unique(&mut self) -> Vec<T>;
 }
@@ -107,6 +108,7 @@ where
 }
 
 /// [UniqueIndices] trait
+#[allow(dead_code)]
 pub trait UniqueIndices<T> {
     fn _unique_idx(&mut self) -> Vec<usize>;
 }
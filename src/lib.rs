@@ -1,18 +1,245 @@
 #![doc = include_str!("../README.md")]
+#![cfg_attr(feature = "no_std", no_std)]
 // --------------------------------------------------
 // external
 // --------------------------------------------------
-use thiserror::Error;
+use core::fmt;
+use alloc::vec::Vec;
+use alloc::string::String;
 pub use thisenum_impl::*;
+extern crate alloc;
 
-#[derive(Error, Debug)]
+#[doc(hidden)]
+/// Re-exports used by the generated code, not public API
+pub mod __private {
+    pub extern crate alloc;
+}
+
+#[derive(Debug)]
+#[non_exhaustive]
 /// All errors that can occur while using [`TryFrom`]
 /// implementation for [`Const`]
 pub enum Error {
-    #[error("Unable to convert `{0}` to `{1}`")]
+    /// Unable to convert `{0}` to `{1}`
     InvalidValue(String, String),
-    #[error("Multiple associated enum arms defined with value `{0}`")]
+    /// Multiple associated enum arms defined with value `{0}`
     UnreachableValue(String),
-    #[error("Unable to return variant `{0}` from constant, since the variant has nested arguments")]
+    /// Unable to return variant `{0}` from constant, since the variant has nested arguments
     UnableToReturnVariant(String),
-}
\ No newline at end of file
+    /// Value `{0}` out of range for `{1}`
+    OutOfRange(String, String),
+    /// Unable to convert `{0}` to `{1}`, expected one of: `{2}`
+    InvalidValueWithCandidates(String, String, Vec<String>),
+}
+
+/// [`Display`](fmt::Display) implementation for [`Error`]
+/// 
+/// Implemented manually rather than with `thiserror`, so
+/// that [`Error`] is available with the `no_std` feature
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::InvalidValue(value, name) => write!(f, "Unable to convert `{}` to `{}`", value, name),
+            Error::UnreachableValue(value) => write!(f, "Multiple associated enum arms defined with value `{}`", value),
+            Error::UnableToReturnVariant(variant) => write!(f, "Unable to return variant `{}` from constant, since the variant has nested arguments", variant),
+            Error::OutOfRange(value, typ) => write!(f, "Value `{}` out of range for `{}`", value, typ),
+            Error::InvalidValueWithCandidates(value, name, candidates) => write!(f, "Unable to convert `{}` to `{}`, expected one of: {}", value, name, candidates.join(", ")),
+        }
+    }
+}
+
+/// [`Error`](std::error::Error) implementation for [`Error`]
+/// 
+/// Not implemented with the `no_std` feature, since `core::error::Error`
+/// requires Rust 1.81
+#[cfg(not(feature = "no_std"))]
+impl std::error::Error for Error {}
+
+/// Tests for values which do not match the armtype, which
+/// must fail to compile. These only check that compilation
+/// fails, the errors themselves are tested in `thisenum-impl`
+/// 
+/// ```compile_fail
+/// #[derive(thisenum::Const)]
+/// #[armtype(u8)]
+/// enum StrForInt {
+///     #[value = "oops"]
+///     A,
+/// }
+/// ```
+/// 
+/// ```compile_fail
+/// #[derive(thisenum::Const)]
+/// #[armtype(&str)]
+/// enum IntForStr {
+///     #[value = 1]
+///     A,
+/// }
+/// ```
+/// 
+/// ```compile_fail
+/// #[derive(thisenum::Const)]
+/// #[armtype(&[u8])]
+/// enum StrForBytes {
+///     #[value = "oops"]
+///     A,
+/// }
+/// ```
+/// 
+/// ```compile_fail
+/// #[derive(thisenum::Const)]
+/// #[armtype(f32)]
+/// enum IntForFloat {
+///     #[value = 1]
+///     A,
+/// }
+/// ```
+/// 
+/// ```compile_fail
+/// #[derive(thisenum::Const)]
+/// #[armtype(u8)]
+/// enum SuffixForInt {
+///     #[value(1u16)]
+///     A,
+/// }
+/// ```
+/// 
+/// ```compile_fail
+/// #[derive(thisenum::Const)]
+/// #[armtype(&[u8; 2])]
+/// enum TooShort {
+///     #[value = b"\x01"]
+///     A,
+/// }
+/// ```
+/// 
+/// ```compile_fail
+/// #[derive(thisenum::Const)]
+/// #[armtype(&[u8; 2])]
+/// enum TooLong {
+///     #[value = b"\x01\x02\x03"]
+///     A,
+/// }
+/// ```
+/// 
+/// ```compile_fail
+/// #[derive(thisenum::ConstEach)]
+/// enum EachStrForInt {
+///     #[armtype(u8)]
+///     #[value = "str"]
+///     A,
+/// }
+/// ```
+/// 
+/// ```compile_fail
+/// #[derive(thisenum::ConstEach)]
+/// #[armtype(f32)]
+/// enum EachBoolForFloat {
+///     #[value = true]
+///     A,
+/// }
+/// ```
+#[doc(hidden)]
+#[cfg(doctest)]
+pub struct MismatchedArmType;
+
+/// Tests for ignored results of generated methods, which
+/// must warn (here denied) since the methods are pure
+/// 
+/// ```compile_fail
+/// #![deny(unused_must_use)]
+/// #[derive(thisenum::Const)]
+/// #[armtype(u8)]
+/// enum Ignored {
+///     #[value = 1]
+///     A,
+/// }
+/// 
+/// fn main() {
+///     Ignored::A.value();
+/// }
+/// ```
+/// 
+/// ```
+/// #![deny(unused_must_use)]
+/// #[derive(thisenum::Const)]
+/// #[armtype(u8)]
+/// enum Used {
+///     #[value = 1]
+///     A,
+/// }
+/// 
+/// fn main() {
+///     let _ = Used::A.value();
+///     assert_eq!(Used::A.value(), &1);
+/// }
+/// ```
+#[doc(hidden)]
+#[cfg(doctest)]
+pub struct MustUse;
+
+/// Tests for computed values of [`ConstEach`] without a type, which are not
+/// `'static` and must fail to compile. These only check that compilation
+/// fails, the errors themselves are tested in `thisenum-impl`
+/// 
+/// ```compile_fail
+/// #[derive(thisenum::ConstEach)]
+/// enum Untyped {
+///     #[value(vec![1, 2, 3])]
+///     A,
+/// }
+/// ```
+/// 
+/// ```compile_fail
+/// #[derive(thisenum::ConstEach)]
+/// enum UntypedCall {
+///     #[value(String::from("a"))]
+///     A,
+/// }
+/// ```
+#[doc(hidden)]
+#[cfg(doctest)]
+pub struct UntypedComputedValue;
+
+/// Tests for repeated values with `#[thisenum(unique)]`, which must fail
+/// to compile. These only check that compilation fails, the errors
+/// themselves are tested in `thisenum-impl`
+/// 
+/// ```compile_fail
+/// #[derive(thisenum::Const)]
+/// #[armtype(u8)]
+/// #[thisenum(unique)]
+/// enum RepeatedInt {
+///     #[value = 1]
+///     A,
+///     #[value = 1]
+///     B,
+/// }
+/// ```
+/// 
+/// ```compile_fail
+/// #[derive(thisenum::Const)]
+/// #[armtype(&str)]
+/// #[thisenum(unique)]
+/// enum RepeatedStr {
+///     #[value = "a"]
+///     A,
+///     #[value = "b"]
+///     B,
+///     #[value = "a"]
+///     C,
+/// }
+/// ```
+/// 
+/// ```compile_fail
+/// #[derive(thisenum::Const)]
+/// #[armtype(f32)]
+/// #[thisenum(unique)]
+/// enum UnsupportedFloat {
+///     #[value = 1.0]
+///     A,
+/// }
+/// ```
+#[doc(hidden)]
+#[cfg(doctest)]
+pub struct RepeatedValues;
@@ -0,0 +1,1954 @@
+#![allow(clippy::approx_constant)]
+
+use thisenum::Const;
+
+#[derive(Const)]
+#[armtype(u16)]
+enum StatusCode {
+    #[value(100..=199)]
+    Informational,
+    #[value(200..=299)]
+    Success,
+    #[value(300..=399)]
+    Redirection,
+    #[value = 404]
+    NotFound,
+}
+
+#[test]
+fn range_value() {
+    assert!(matches!(StatusCode::try_from(250u16), Ok(StatusCode::Success)));
+    assert!(matches!(StatusCode::try_from(200u16), Ok(StatusCode::Success)));
+    assert!(matches!(StatusCode::try_from(299u16), Ok(StatusCode::Success)));
+    assert!(matches!(StatusCode::try_from(101u16), Ok(StatusCode::Informational)));
+    assert!(matches!(StatusCode::try_from(404u16), Ok(StatusCode::NotFound)));
+    assert!(StatusCode::try_from(500u16).is_err());
+    assert_eq!(StatusCode::Success.value(), &200);
+    assert_eq!(StatusCode::Redirection.value(), &300);
+    assert_eq!(StatusCode::NotFound.value(), &404);
+}
+
+#[derive(Const)]
+#[armtype(&[u8])]
+enum Tags {
+    #[value = b"\x00\x01\x7f"]
+    Key,
+    #[value = b"\xba\x5e"]
+    Length,
+    #[value = b"data"]
+    Data,
+}
+
+#[test]
+fn try_from_str() {
+    assert!(matches!(Tags::try_from("data"), Ok(Tags::Data)));
+    assert!(matches!(Tags::try_from(b"data" as &[u8]), Ok(Tags::Data)));
+    assert!(matches!(Tags::try_from("\x00\x01\x7f"), Ok(Tags::Key)));
+    assert!(Tags::try_from("nope").is_err());
+}
+
+#[derive(Const)]
+enum Inferred {
+    #[value = 0]
+    A,
+    #[value = 1]
+    B,
+    #[value = 0x7f]
+    C,
+}
+
+#[derive(Const)]
+enum InferredStr {
+    #[value = "foo"]
+    Foo,
+    #[value = "bar"]
+    Bar,
+}
+
+#[test]
+fn inferred_armtype() {
+    assert_eq!(Inferred::A.value(), &0_i32);
+    assert_eq!(Inferred::C.value(), &0x7f_i32);
+    assert!(matches!(Inferred::try_from(1), Ok(Inferred::B)));
+    assert_eq!(InferredStr::Bar.value(), "bar");
+    assert!(matches!(InferredStr::try_from("foo"), Ok(InferredStr::Foo)));
+}
+
+#[test]
+fn parse_all() {
+    let (parsed, rest) = Tags::parse_all(b"\xba\x5e\x00\x01\x7fdata\xff\xff");
+    assert!(matches!(parsed.as_slice(), [Tags::Length, Tags::Key, Tags::Data]));
+    assert_eq!(rest, b"\xff\xff");
+    let (parsed, rest) = Tags::parse_all(b"\xff\xba\x5e");
+    assert!(parsed.is_empty());
+    assert_eq!(rest, b"\xff\xba\x5e");
+}
+
+#[derive(Const)]
+#[armtype(u128)]
+enum Wide {
+    #[value = 0x1_0000_0000_0000_0000]
+    Above,
+    #[value = 340282366920938463463374607431768211455]
+    Max,
+}
+
+#[derive(Const)]
+#[armtype(i128)]
+enum WideSigned {
+    #[value = 0x1_0000_0000_0000_0000]
+    Above,
+}
+
+#[test]
+fn wide_integers() {
+    assert_eq!(Wide::Above.value(), &(u64::MAX as u128 + 1));
+    assert_eq!(Wide::Max.value(), &u128::MAX);
+    assert!(matches!(Wide::try_from(u64::MAX as u128 + 1), Ok(Wide::Above)));
+    assert!(matches!(Wide::try_from(u128::MAX), Ok(Wide::Max)));
+    assert!(Wide::try_from(u64::MAX as u128).is_err());
+    let value: u128 = Wide::Above.into();
+    assert_eq!(value, u64::MAX as u128 + 1);
+    assert!(matches!(WideSigned::try_from(u64::MAX as i128 + 1), Ok(WideSigned::Above)));
+    #[cfg(feature = "eq")]
+    assert_eq!(Wide::Max, u128::MAX);
+}
+
+fn as_bytes(value: impl AsRef<[u8]>) -> Vec<u8> {
+    value.as_ref().to_vec()
+}
+
+#[test]
+fn as_ref() {
+    assert_eq!(as_bytes(Tags::Key), b"\x00\x01\x7f");
+    assert_eq!(AsRef::<str>::as_ref(&InferredStr::Foo), "foo");
+    assert_eq!(AsRef::<u16>::as_ref(&StatusCode::NotFound), &404);
+}
+
+#[derive(Const)]
+#[armtype(&[u8], ord)]
+enum OrdTags {
+    #[value = b"\x00\x01\x7f"]
+    Key,
+    #[value = b"\xba\x5e"]
+    Length,
+    #[value = b"data"]
+    Data,
+}
+
+#[derive(Const)]
+#[armtype(u16, hash)]
+enum HashCodes {
+    #[value = 200]
+    Success,
+    #[value = 404]
+    NotFound,
+}
+
+#[derive(Const)]
+#[armtype(f32, self_eq)]
+enum EqFloats {
+    #[value = 1.0]
+    One,
+    #[value = 1.0]
+    Also,
+    #[value = 2.0]
+    Two,
+}
+
+#[test]
+fn borrow() {
+    use std::collections::{BTreeMap, HashMap};
+    let btree = BTreeMap::from([(OrdTags::Key, 1), (OrdTags::Length, 2)]);
+    assert_eq!(btree.get(&b"\xba\x5e"[..]), Some(&2));
+    assert_eq!(btree.get(&b"data"[..]), None);
+    let hash = HashMap::from([(HashCodes::Success, "ok"), (HashCodes::NotFound, "not found")]);
+    assert_eq!(hash.get(&404), Some(&"not found"));
+    assert_eq!(hash.get(&200), Some(&"ok"));
+    assert!(OrdTags::Key < OrdTags::Length);
+    assert!(OrdTags::Data > OrdTags::Key);
+    assert_eq!(HashCodes::Success, HashCodes::Success);
+}
+
+#[test]
+fn self_eq() {
+    assert_eq!(EqFloats::One, EqFloats::Also);
+    assert_ne!(EqFloats::One, EqFloats::Two);
+}
+
+const fn compute_tag(name: &str) -> u8 {
+    name.len() as u8 * 2
+}
+
+#[derive(Const)]
+#[armtype(u8)]
+enum Computed {
+    #[value(compute_tag("width"))]
+    Width,
+    #[value(compute_tag("height"))]
+    Height,
+    #[value = 0]
+    Zero,
+}
+
+#[test]
+fn const_fn_value() {
+    assert_eq!(Computed::Width.value(), &10);
+    assert_eq!(Computed::Height.value(), &12);
+    assert_eq!(Computed::Zero.value(), &0);
+    assert!(matches!(Computed::try_from(10u8), Ok(Computed::Width)));
+    assert!(matches!(Computed::try_from(12u8), Ok(Computed::Height)));
+    assert!(Computed::try_from(11u8).is_err());
+}
+
+#[test]
+fn matches_value() {
+    assert!(Tags::Key.matches_value(b"\x00\x01\x7f"));
+    assert!(!Tags::Key.matches_value(b"\xba\x5e"));
+    assert!(StatusCode::NotFound.matches_value(&404));
+    assert!(InferredStr::Foo.matches_value("foo"));
+}
+
+mod reexport {
+    pub use thisenum::*;
+}
+
+#[derive(Const)]
+#[allow(dead_code)]
+#[armtype(u8)]
+#[thisenum(crate = crate::reexport)]
+enum Reexported {
+    #[value = 1]
+    A,
+    #[value = 1]
+    B,
+    #[value = 2]
+    C(u8),
+}
+
+#[test]
+fn crate_path() {
+    assert_eq!(Reexported::A.value(), &1);
+    assert!(matches!(Reexported::try_from(1u8), Err(reexport::Error::UnreachableValue(_))));
+    assert!(matches!(Reexported::try_from(2u8), Err(reexport::Error::UnableToReturnVariant(_))));
+    assert!(matches!(Reexported::try_from(3u8), Err(reexport::Error::InvalidValue(..))));
+}
+
+#[derive(Const)]
+#[armtype(f64)]
+enum Float {
+    #[value = 3.14]
+    Pi,
+    #[value = 2.71]
+    E,
+    #[value(-0.0)]
+    NegZero,
+}
+
+#[test]
+fn float_try_from() {
+    assert_eq!(Float::Pi.value(), &3.14);
+    assert!(matches!(Float::try_from(3.14f64), Ok(Float::Pi)));
+    assert!(matches!(Float::try_from(2.71), Ok(Float::E)));
+    assert!(Float::try_from(3.1400001).is_err());
+    assert!(matches!(Float::try_from(-0.0), Ok(Float::NegZero)));
+    assert!(Float::try_from(0.0).is_err());
+    assert!(Float::try_from(f64::NAN).is_err());
+}
+
+#[derive(Const)]
+#[allow(dead_code)]
+#[armtype(&[u8])]
+enum ManyTags {
+    #[value = b"\x01\x06"]
+    A,
+    #[value = b"\x01\x00"]
+    B,
+    #[value = b"\x01"]
+    C,
+    #[value = b"\x01\x00\x00"]
+    D(u8),
+    #[value = b"\xff"]
+    E,
+    #[value = b"\xff"]
+    F,
+    #[value = b""]
+    G,
+    #[value = b"\x01\x02"]
+    H,
+}
+
+#[test]
+fn byte_table() {
+    assert!(matches!(ManyTags::try_from(b"\x01\x06" as &[u8]), Ok(ManyTags::A)));
+    assert!(matches!(ManyTags::try_from(b"\x01\x00" as &[u8]), Ok(ManyTags::B)));
+    assert!(matches!(ManyTags::try_from(b"\x01" as &[u8]), Ok(ManyTags::C)));
+    assert!(matches!(ManyTags::try_from(b"" as &[u8]), Ok(ManyTags::G)));
+    assert!(matches!(ManyTags::try_from(b"\x01\x02" as &[u8]), Ok(ManyTags::H)));
+    assert!(matches!(ManyTags::try_from(b"\x01\x00\x00" as &[u8]), Err(thisenum::Error::UnableToReturnVariant(v)) if v == "D"));
+    assert!(matches!(ManyTags::try_from(b"\xff" as &[u8]), Err(thisenum::Error::UnreachableValue(v)) if v == "[255]"));
+    assert!(matches!(ManyTags::try_from(b"\x02" as &[u8]), Err(thisenum::Error::InvalidValue(..))));
+    assert!(matches!(ManyTags::try_from(b"\x01\x06\x00" as &[u8]), Err(thisenum::Error::InvalidValue(..))));
+}
+
+#[derive(Const)]
+#[armtype(&str)]
+enum MyStrEnum {
+    #[value = "this"]
+    This,
+    #[value = "that"]
+    That,
+}
+
+#[test]
+fn try_from_string() {
+    assert!(matches!(MyStrEnum::try_from("this".to_string()), Ok(MyStrEnum::This)));
+    assert!(matches!(MyStrEnum::try_from(String::from("that")), Ok(MyStrEnum::That)));
+    assert!(matches!(MyStrEnum::try_from("other".to_string()), Err(thisenum::Error::InvalidValue(v, _)) if v == "\"other\""));
+}
+
+#[derive(Const)]
+#[armtype(&[u8], no_inline)]
+enum NoInline {
+    #[value = b"\x01"]
+    A,
+    #[value = b"\x02"]
+    B,
+}
+
+#[test]
+fn no_inline() {
+    assert_eq!(NoInline::A.value(), b"\x01");
+    assert!(NoInline::B.matches_value(b"\x02"));
+    assert!(matches!(NoInline::try_from(b"\x02" as &[u8]), Ok(NoInline::B)));
+    assert!(matches!(NoInline::try_from("\x01"), Ok(NoInline::A)));
+}
+
+#[derive(Const)]
+#[armtype(u8,)]
+enum TrailingComma {
+    #[value = 1]
+    A,
+}
+
+#[test]
+fn armtype_trailing_comma() {
+    assert_eq!(TrailingComma::A.value(), &1u8);
+    assert!(matches!(TrailingComma::try_from(1u8), Ok(TrailingComma::A)));
+}
+
+#[derive(Const)]
+#[armtype(u8)]
+enum Protocol {
+    #[value = 0x01]
+    Ping,
+    #[value = 0x02]
+    Pong,
+    #[value(_)]
+    Other(u8),
+}
+
+#[test]
+fn catch_all() {
+    assert!(matches!(Protocol::try_from(0x01u8), Ok(Protocol::Ping)));
+    assert!(matches!(Protocol::try_from(0x02u8), Ok(Protocol::Pong)));
+    assert!(matches!(Protocol::try_from(0x7fu8), Ok(Protocol::Other(0x7f))));
+    assert_eq!(Protocol::Other(0x7f).value(), &0x7f);
+    assert_eq!(Protocol::Pong.value(), &0x02);
+    assert_eq!(format!("{:?}", Protocol::Other(3)), "Protocol::Other: 3");
+}
+
+#[derive(Const)]
+#[armtype(i32)]
+enum Owned {
+    #[value = 0]
+    A,
+    #[value = 1]
+    B,
+}
+
+#[test]
+fn into_value() {
+    assert_eq!(Owned::A.into_value(), 0);
+    assert_eq!(Owned::B.into_value(), 1);
+    assert_eq!(Protocol::Other(9).into_value(), 9);
+    assert_eq!(Tags::Key.into_value(), b"\x00\x01\x7f");
+}
+
+mod consts {
+    pub mod tags {
+        pub const WIDTH: u16 = 0x0100;
+        pub const NAME: &str = "name";
+    }
+    pub const TABLE: [u16; 3] = [0x0200, 0x0201, 0x0202];
+}
+
+#[derive(Const)]
+#[armtype(u16)]
+enum PathValue {
+    #[value(consts::tags::WIDTH)]
+    Width,
+    #[value = 0x0101]
+    Height,
+}
+
+#[derive(Const)]
+#[armtype(&str)]
+enum PathValueStr {
+    #[value(crate::consts::tags::NAME)]
+    Name,
+    #[value = "other"]
+    Other,
+}
+
+#[derive(Const)]
+#[armtype(u16)]
+enum TableValue {
+    #[value(consts::TABLE[0])]
+    First,
+    #[value(consts::TABLE[2])]
+    Last,
+}
+
+const TABLE_LAST: &u16 = TableValue::Last.value();
+
+#[test]
+fn table_value() {
+    assert_eq!(TableValue::First.value(), &0x0200);
+    assert_eq!(TABLE_LAST, &consts::TABLE[2]);
+    assert!(matches!(TableValue::try_from(0x0200u16), Ok(TableValue::First)));
+    assert!(matches!(TableValue::try_from(0x0202u16), Ok(TableValue::Last)));
+    assert!(TableValue::try_from(0x0201u16).is_err());
+}
+
+#[test]
+fn path_value() {
+    assert_eq!(PathValue::Width.value(), &consts::tags::WIDTH);
+    assert_eq!(PathValue::Width.into_value(), 0x0100);
+    let width: u16 = PathValue::Width.into();
+    assert_eq!(width, 0x0100);
+    assert!(matches!(PathValue::try_from(0x0100u16), Ok(PathValue::Width)));
+    assert!(matches!(PathValue::try_from(0x0101u16), Ok(PathValue::Height)));
+    assert!(PathValue::try_from(0x0102u16).is_err());
+    assert_eq!(PathValueStr::Name.value(), "name");
+    assert!(matches!(PathValueStr::try_from("name"), Ok(PathValueStr::Name)));
+    assert!(matches!(PathValueStr::try_from("other"), Ok(PathValueStr::Other)));
+    #[cfg(feature = "eq")]
+    {
+        assert_eq!(PathValue::Width, 0x0100);
+        assert_eq!(PathValueStr::Name, "name");
+    }
+}
+
+#[derive(Debug, Clone, Copy, Eq, PartialOrd, Ord)]
+struct Version(u8, u8);
+
+impl PartialEq for Version {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0 && self.1 == other.1
+    }
+}
+
+impl std::hash::Hash for Version {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        (self.0, self.1).hash(state);
+    }
+}
+
+const fn version(major: u8) -> Version {
+    Version(major, 0)
+}
+
+#[derive(Const)]
+#[armtype(Version)]
+enum Versioned {
+    #[value(version(1))]
+    V1,
+    #[value(version(2))]
+    V2,
+}
+
+#[test]
+fn non_pattern_value() {
+    assert_eq!(Versioned::V1.value(), &Version(1, 0));
+    assert!(matches!(Versioned::try_from(Version(1, 0)), Ok(Versioned::V1)));
+    assert!(matches!(Versioned::try_from(Version(2, 0)), Ok(Versioned::V2)));
+    assert!(Versioned::try_from(Version(3, 0)).is_err());
+}
+
+#[allow(dead_code)]
+#[derive(Const)]
+#[armtype(u8)]
+enum Filled {
+    #[value = 1]
+    Unit,
+    #[value = 2]
+    #[fill(a = 1, b = 2)]
+    Named { a: u8, b: u8 },
+    #[value = 3]
+    #[fill(Default, b = 5)]
+    Partial { a: u8, b: u8 },
+    #[value = 4]
+    #[fill(Default)]
+    Unnamed(u8, String),
+    #[value = 5]
+    Unfilled { a: u8 },
+}
+
+#[test]
+fn fill() {
+    assert!(matches!(Filled::try_from(1u8), Ok(Filled::Unit)));
+    assert!(matches!(Filled::try_from(2u8), Ok(Filled::Named { a: 1, b: 2 })));
+    assert!(matches!(Filled::try_from(3u8), Ok(Filled::Partial { a: 0, b: 5 })));
+    assert!(matches!(Filled::try_from(4u8), Ok(Filled::Unnamed(0, s)) if s.is_empty()));
+    assert!(matches!(Filled::try_from(5u8), Err(thisenum::Error::UnableToReturnVariant(_))));
+    assert_eq!(Filled::Named { a: 0, b: 0 }.value(), &2);
+    assert_eq!(Filled::Unfilled { a: 0 }.value(), &5);
+}
+
+#[derive(Const)]
+#[armtype(u16, be)]
+enum BigEndian {
+    #[value = 0x0102]
+    A,
+}
+
+#[test]
+fn value_bytes() {
+    assert_eq!(Tags::Length.value_bytes(), &b"\xba\x5e"[..]);
+    assert!(matches!(Tags::Length.value_bytes(), std::borrow::Cow::Borrowed(_)));
+    assert_eq!(PathValue::Width.value_bytes(), &[0x00, 0x01][..]);
+    assert_eq!(BigEndian::A.value_bytes(), &[0x01, 0x02][..]);
+    assert_eq!(InferredStr::Foo.value_bytes(), &b"foo"[..]);
+}
+
+#[derive(Const)]
+#[armtype(u8)]
+enum Generic<T> {
+    #[value = 0]
+    A,
+    #[value = 1]
+    B,
+    _Phantom(std::marker::PhantomData<T>),
+}
+
+#[test]
+fn phantom_generic() {
+    assert_eq!(Generic::<String>::A.value(), &0);
+    assert_eq!(Generic::<String>::B.into_value(), 1);
+    assert!(matches!(Generic::<String>::try_from(1u8), Ok(Generic::B)));
+    assert!(Generic::<String>::try_from(2u8).is_err());
+    assert_eq!(format!("{:?}", Generic::<String>::A), "Generic::A: 0");
+    assert_eq!(format!("{:?}", Generic::<String>::_Phantom(std::marker::PhantomData)), "Generic::_Phantom");
+}
+
+#[allow(dead_code)]
+#[derive(Const)]
+#[armtype(&[u8])]
+enum Borrowed<'a, T: Clone> where T: Default {
+    #[value = b"a"]
+    A,
+    #[value = b"b"]
+    B(&'a str),
+    _Phantom(std::marker::PhantomData<T>),
+}
+
+#[test]
+fn lifetime_generic() {
+    let s = String::from("borrowed");
+    let b = Borrowed::<u8>::B(&s);
+    assert_eq!(b.value(), b"b");
+    assert!(matches!(Borrowed::<u8>::try_from(b"a" as &[u8]), Ok(Borrowed::A)));
+    assert!(matches!(Borrowed::<u8>::try_from(b"b" as &[u8]), Err(thisenum::Error::UnableToReturnVariant(_))));
+    assert!(matches!(Borrowed::<u8>::try_from("a"), Ok(Borrowed::A)));
+    assert_eq!(Borrowed::<u8>::A.as_ref(), b"a");
+    #[cfg(feature = "eq")]
+    assert_eq!(b, b"b" as &[u8]);
+}
+
+#[derive(Const)]
+#[armtype(u8, auto = increment)]
+enum Auto {
+    A,
+    B,
+    C,
+    #[value = 10]
+    D,
+    E,
+}
+
+#[derive(Const)]
+#[armtype(i16, auto = increment(-2))]
+enum AutoStart {
+    A,
+    B,
+    C,
+}
+
+#[test]
+fn auto_increment() {
+    assert_eq!(Auto::A.value(), &0);
+    assert_eq!(Auto::B.value(), &1);
+    assert_eq!(Auto::C.value(), &2);
+    assert_eq!(Auto::D.value(), &10);
+    assert_eq!(Auto::E.value(), &11);
+    assert!(matches!(Auto::try_from(11u8), Ok(Auto::E)));
+    assert_eq!(AutoStart::A.value(), &-2);
+    assert_eq!(AutoStart::C.value(), &0);
+}
+
+#[derive(Const)]
+#[armtype(&str)]
+enum RawStr {
+    #[value = r"C:\path\to\file"]
+    Path,
+    #[value = r#"say "hi"\n"#]
+    Quoted,
+}
+
+#[derive(Const)]
+#[armtype(&[u8])]
+enum RawBytes {
+    #[value = br"\x00"]
+    Escaped,
+}
+
+#[test]
+fn raw_string() {
+    assert_eq!(RawStr::Path.value(), "C:\\path\\to\\file");
+    assert_eq!(RawStr::Quoted.value(), "say \"hi\"\\n");
+    assert!(matches!(RawStr::try_from("C:\\path\\to\\file"), Ok(RawStr::Path)));
+    assert_eq!(RawBytes::Escaped.value(), b"\\x00");
+    assert!(matches!(RawBytes::try_from(b"\\x00" as &[u8]), Ok(RawBytes::Escaped)));
+}
+
+#[derive(Const)]
+#[allow(dead_code)]
+#[armtype(u8)]
+enum TestU8 {
+    #[value = 0x7f]
+    A,
+    #[value = 0x3b]
+    B,
+}
+
+#[cfg(feature = "widen")]
+#[test]
+fn widen() {
+    assert!(matches!(TestU8::try_from(0x7fu16), Ok(TestU8::A)));
+    assert!(matches!(TestU8::try_from(0x7fu128), Ok(TestU8::A)));
+    assert!(matches!(TestU8::try_from(0x100u16), Err(thisenum::Error::OutOfRange(..))));
+    assert!(matches!(TestU8::try_from(0x7eu32), Err(thisenum::Error::InvalidValue(..))));
+    let e = TestU8::try_from(300u16).unwrap_err();
+    assert_eq!(e.to_string(), "Value `300` out of range for `u8`");
+}
+
+#[cfg(not(feature = "no_std"))]
+#[test]
+fn error_trait() {
+    let e: Box<dyn std::error::Error> = Box::new(TestU8::try_from(0x7eu8).unwrap_err());
+    assert_eq!(e.to_string(), "Unable to convert `126` to `TestU8`");
+}
+
+#[derive(Const)]
+#[armtype(u8)]
+enum Skipped {
+    #[value = 1]
+    Current,
+    #[skip]
+    #[value = 1]
+    Deprecated,
+    #[skip]
+    #[value = 2]
+    Internal,
+}
+
+#[test]
+fn skip() {
+    assert_eq!(Skipped::Deprecated.value(), &1);
+    assert_eq!(Skipped::Internal.value(), &2);
+    assert!(matches!(Skipped::try_from(1u8), Ok(Skipped::Current)));
+    assert!(matches!(Skipped::try_from(2u8), Err(thisenum::Error::InvalidValue(..))));
+}
+
+#[derive(Const)]
+#[armtype((u8, u8))]
+enum Tuple {
+    #[value((1, 2))]
+    A,
+    #[value((3, 4))]
+    B,
+}
+
+#[test]
+fn tuple_value() {
+    assert_eq!(Tuple::A.value(), &(1, 2));
+    assert_eq!(Tuple::B.into_value(), (3, 4));
+    assert!(matches!(Tuple::try_from((1, 2)), Ok(Tuple::A)));
+    assert!(matches!(Tuple::try_from((3, 4)), Ok(Tuple::B)));
+    assert!(Tuple::try_from((2, 1)).is_err());
+    #[cfg(feature = "eq")]
+    {
+        assert_eq!(Tuple::A, (1, 2));
+        assert_eq!((3, 4), Tuple::B);
+    }
+}
+
+#[derive(Const, Clone)]
+#[armtype(u8)]
+enum Owning {
+    #[value = 1]
+    Unit,
+    #[value = 2]
+    Name(String),
+    #[value = 3]
+    Named { name: String, id: u32 },
+}
+
+#[test]
+fn clone_with_fields() {
+    let name = Owning::Name("foo".to_string());
+    let named = Owning::Named { name: "bar".to_string(), id: 7 };
+    assert_eq!(format!("{:?}", name), "Owning::Name: 2");
+    assert_eq!(format!("{:?}", named), "Owning::Named: 3");
+    assert_eq!(name.value(), &2);
+    assert_eq!(named.value(), &3);
+    assert!(name.matches_value(&2));
+    // fields are still owned after `value` and `Debug`
+    assert!(matches!(name.clone(), Owning::Name(s) if s == "foo"));
+    assert!(matches!(named, Owning::Named { ref name, id: 7 } if name == "bar"));
+    assert_eq!(Owning::Unit.clone().into_value(), 1);
+    assert_eq!(name.into_value(), 2);
+}
+
+#[test]
+fn from_value_ref() {
+    assert!(matches!(Owned::from_value_ref(&1), Ok(Owned::B)));
+    assert!(Owned::from_value_ref(&2).is_err());
+    assert!(matches!(Tags::from_value_ref(b"data"), Ok(Tags::Data)));
+    assert!(matches!(InferredStr::from_value_ref("bar"), Ok(InferredStr::Bar)));
+    assert!(matches!(Versioned::from_value_ref(&Version(2, 0)), Ok(Versioned::V2)));
+    assert!(matches!(Protocol::from_value_ref(&0x10), Ok(Protocol::Other(0x10))));
+    assert!(matches!(StatusCode::from_value_ref(&250), Ok(StatusCode::Success)));
+}
+
+#[derive(Const)]
+#[armtype(&[u8])]
+#[value_prefix = b"\xE0\x00"]
+enum Prefixed {
+    #[value = b"\x01"]
+    A,
+    #[value = b"\x02\x03"]
+    B,
+}
+
+#[derive(Const)]
+#[value_prefix = "tag:"]
+enum PrefixedStr {
+    #[value = "a"]
+    A,
+    #[value = "b"]
+    B,
+}
+
+#[test]
+fn value_prefix() {
+    assert_eq!(Prefixed::A.value(), b"\xE0\x00\x01");
+    assert_eq!(Prefixed::B.value(), b"\xE0\x00\x02\x03");
+    assert!(matches!(Prefixed::try_from(b"\xE0\x00\x02\x03" as &[u8]), Ok(Prefixed::B)));
+    assert!(Prefixed::try_from(b"\x01" as &[u8]).is_err());
+    assert_eq!(PrefixedStr::B.value(), "tag:b");
+    assert!(matches!(PrefixedStr::try_from("tag:a"), Ok(PrefixedStr::A)));
+}
+
+#[derive(Const)]
+#[armtype(u8, default)]
+enum Config {
+    #[value = 0]
+    Off,
+    #[default]
+    #[value = 1]
+    Auto,
+    #[value = 2]
+    On,
+}
+
+#[derive(Const, Default)]
+#[armtype(u8)]
+enum StdDefault {
+    #[value = 0]
+    Off,
+    #[default]
+    #[value = 1]
+    On,
+}
+
+#[test]
+fn default_variant() {
+    assert!(matches!(Config::default(), Config::Auto));
+    assert_eq!(Config::default().value(), &1);
+    assert!(matches!(Config::try_from(0u8), Ok(Config::Off)));
+    assert!(matches!(Config::try_from(2u8), Ok(Config::On)));
+    assert!(matches!(StdDefault::default(), StdDefault::On));
+    assert!(matches!(StdDefault::from_value_or_default(0), StdDefault::Off));
+    assert!(matches!(StdDefault::from_value_or_default(42), StdDefault::On));
+}
+
+#[derive(Const)]
+#[const_type(u8)]
+enum LongForm {
+    #[const_value = 1]
+    A,
+    #[const_value(2)]
+    B,
+}
+
+#[test]
+fn long_form_attributes() {
+    assert_eq!(LongForm::A.value(), &1);
+    assert_eq!(LongForm::B.value(), &2);
+    assert!(matches!(LongForm::try_from(2u8), Ok(LongForm::B)));
+}
+
+#[cfg(feature = "eq")]
+#[test]
+fn eq_reference() {
+    assert_eq!(Owned::A, &0);
+    assert_eq!(&1, Owned::B);
+    assert_ne!(Owned::A, &1);
+    assert_eq!(Tags::Key, &(b"\x00\x01\x7f" as &[u8]));
+    assert!([0, 1].iter().zip([Owned::A, Owned::B]).all(|(v, e)| e == v));
+}
+
+#[derive(Const)]
+#[armtype(&[&str])]
+enum Aliases {
+    #[value(&["alias1", "alias2"])]
+    A,
+    #[value(&["other"])]
+    B,
+}
+
+#[test]
+fn str_aliases() {
+    assert_eq!(Aliases::A.value(), &["alias1", "alias2"]);
+    assert!(matches!(Aliases::try_from("alias2"), Ok(Aliases::A)));
+    assert!(matches!(Aliases::try_from("other"), Ok(Aliases::B)));
+    assert!(Aliases::try_from("missing").is_err());
+    assert!(matches!(Aliases::try_from(&["other"] as &[&str]), Ok(Aliases::B)));
+}
+
+#[test]
+fn from_bytes() {
+    assert!(matches!(Tags::from_bytes(b"\xba\x5e"), Ok(Tags::Length)));
+    assert!(matches!(Tags::from_bytes(b"data"), Ok(Tags::Data)));
+    assert!(Tags::from_bytes(b"").is_err());
+}
+
+#[derive(Const)]
+#[armtype(&[u8], from = u16_be)]
+enum Registers {
+    #[value = 0x1234]
+    Control,
+    #[value = 0xff]
+    Status,
+}
+
+#[derive(Const)]
+#[armtype(&[u8], from = i32_le)]
+enum Offsets {
+    #[value = 1]
+    Forward,
+    #[value(-2)]
+    Backward,
+}
+
+#[derive(Const)]
+#[armtype(&[u8], from = u128_le)]
+enum WideRegisters {
+    #[value = 340282366920938463463374607431768211455]
+    Max,
+    #[value = 170141183460469231731687303715884105728]
+    AboveSigned,
+}
+
+#[derive(Const)]
+#[armtype(&[u8], from = i128_be)]
+enum WideOffsets {
+    #[value(-170141183460469231731687303715884105728)]
+    Min,
+}
+
+#[test]
+fn from_int() {
+    assert_eq!(Registers::Control.value(), b"\x12\x34");
+    assert_eq!(Registers::Status.value(), b"\x00\xff");
+    assert!(matches!(Registers::from_bytes(b"\x12\x34"), Ok(Registers::Control)));
+    assert_eq!(Offsets::Forward.value(), b"\x01\x00\x00\x00");
+    assert_eq!(Offsets::Backward.value(), b"\xfe\xff\xff\xff");
+    assert_eq!(WideRegisters::Max.value(), &u128::MAX.to_le_bytes());
+    assert_eq!(WideRegisters::AboveSigned.value(), &(1u128 << 127).to_le_bytes());
+    assert_eq!(WideOffsets::Min.value(), &i128::MIN.to_be_bytes());
+}
+
+#[derive(Const)]
+#[armtype(&[u8; 2])]
+enum FixedTags {
+    #[value = b"ab"]
+    A,
+    #[value = b"\x00\x01"]
+    B,
+}
+
+#[test]
+fn byte_array() {
+    assert_eq!(FixedTags::A.value(), b"ab");
+    assert_eq!(FixedTags::B.value(), &[0, 1]);
+    assert!(matches!(FixedTags::try_from(b"\x00\x01"), Ok(FixedTags::B)));
+}
+
+#[derive(Const)]
+#[armtype([u8; 4])]
+enum Repeated {
+    #[value([0u8; 4])]
+    Zero,
+    #[value([0xff; 4])]
+    Ones,
+    #[value([1, 2, 3, 4])]
+    Sequence,
+}
+
+#[derive(Const)]
+#[armtype(&[u8; 4])]
+enum RepeatedRef {
+    #[value(&[0u8; 4])]
+    Zero,
+}
+
+#[test]
+fn repeat_array() {
+    assert_eq!(Repeated::Zero.value(), &[0, 0, 0, 0]);
+    assert_eq!(Repeated::Ones.value(), &[0xff, 0xff, 0xff, 0xff]);
+    assert!(matches!(Repeated::try_from([0, 0, 0, 0]), Ok(Repeated::Zero)));
+    assert!(matches!(Repeated::try_from([1, 2, 3, 4]), Ok(Repeated::Sequence)));
+    assert!(Repeated::try_from([1; 4]).is_err());
+    assert_eq!(RepeatedRef::Zero.value(), &[0, 0, 0, 0]);
+    assert!(matches!(RepeatedRef::try_from(&[0; 4]), Ok(RepeatedRef::Zero)));
+}
+
+#[test]
+fn which() {
+    assert!(matches!(Tags::which(b"\xba\x5e"), Some(Tags::Length)));
+    assert!(Tags::which(b"nope").is_none());
+    assert!(matches!(Filled::which(&2), Some(Filled::Named { a: 1, b: 2 })));
+    assert!(Filled::which(&5).is_none());
+}
+
+#[derive(Const)]
+#[allow(dead_code)]
+/// Docs before the armtype
+#[armtype(u8)]
+/// Docs after the armtype
+enum Ordered {
+    #[value = 1]
+    /// Docs after the value
+    ValueFirst,
+    /// Docs before the value
+    #[value = 2]
+    ValueLast,
+    /// Docs before the value
+    #[allow(clippy::all)]
+    /// Docs between attributes
+    #[value = 3]
+    #[allow(unused)]
+    Interleaved,
+    #[cfg(test)]
+    /// Docs after a `cfg`
+    #[value = 4]
+    CfgEnabled,
+    #[value = 4]
+    #[cfg(not(test))]
+    CfgDisabled,
+    #[cfg_attr(test, value = 5)]
+    CfgAttr,
+}
+
+#[test]
+fn attribute_order() {
+    assert_eq!(Ordered::ValueFirst.value(), &1);
+    assert_eq!(Ordered::ValueLast.value(), &2);
+    assert_eq!(Ordered::Interleaved.value(), &3);
+    assert_eq!(Ordered::CfgEnabled.value(), &4);
+    assert_eq!(Ordered::CfgAttr.value(), &5);
+    assert!(matches!(Ordered::try_from(4u8), Ok(Ordered::CfgEnabled)));
+}
+
+const TAG_COUNT: usize = Tags::ALL.len();
+static STATUS_CODES: [StatusCode; 4] = StatusCode::ALL;
+
+#[test]
+fn all_variants() {
+    assert_eq!(TAG_COUNT, 3);
+    assert!(matches!(Tags::ALL, [Tags::Key, Tags::Length, Tags::Data]));
+    assert_eq!(STATUS_CODES.iter().map(|code| *code.value()).collect::<Vec<_>>(), [100, 200, 300, 404]);
+    assert_eq!(Skipped::ALL.len(), 1);
+    assert!(matches!(Skipped::ALL, [Skipped::Current]));
+    assert_eq!(Skipped::values_map().len(), 1);
+    assert_eq!(Skipped::values_sorted(), [1]);
+    assert_eq!(Skipped::Current.to_index(), 0);
+    assert!(Skipped::from_index(1).is_none());
+}
+
+#[test]
+#[should_panic(expected = "Skipped::Deprecated is skipped, and has no index")]
+fn skipped_index() {
+    let _ = Skipped::Deprecated.to_index();
+}
+
+#[derive(Const)]
+#[armtype(::core::primitive::u8)]
+enum QualifiedInt {
+    #[value = 1]
+    A,
+    #[value(2..=9)]
+    B,
+    #[value = b'z']
+    C,
+}
+
+#[derive(Const)]
+#[armtype(&std::primitive::str)]
+enum QualifiedStr {
+    #[value = "a"]
+    A,
+}
+
+#[test]
+fn qualified_primitive() {
+    assert_eq!(QualifiedInt::A.value(), &1u8);
+    assert!(matches!(QualifiedInt::try_from(5u8), Ok(QualifiedInt::B)));
+    assert!(matches!(QualifiedInt::try_from(b'z'), Ok(QualifiedInt::C)));
+    assert_eq!(QualifiedInt::A.value_bytes(), [1u8].as_slice());
+    let value: u8 = QualifiedInt::C.into();
+    assert_eq!(value, b'z');
+    assert_eq!(QualifiedStr::A.value(), "a");
+    assert!(matches!(QualifiedStr::try_from("a"), Ok(QualifiedStr::A)));
+    assert!(matches!(QualifiedStr::try_from(String::from("a")), Ok(QualifiedStr::A)));
+}
+
+#[derive(Const)]
+#[allow(dead_code)]
+#[armtype(f32)]
+enum SortedFloats {
+    #[value = 2.5]
+    A,
+    #[value(-1.0)]
+    B,
+    #[value = 0.5]
+    C,
+}
+
+#[test]
+fn values_sorted() {
+    assert_eq!(TestU8::values_sorted(), [0x3b, 0x7f]);
+    assert_eq!(SortedFloats::values_sorted(), [-1.0, 0.5, 2.5]);
+}
+
+#[test]
+fn values_map() {
+    let map = Tags::values_map();
+    assert_eq!(map.len(), Tags::ALL.len());
+    assert!(matches!(map[1], (Tags::Length, b"\xba\x5e")));
+    let values = StatusCode::values_map().iter().map(|(_, value)| **value).collect::<Vec<_>>();
+    assert_eq!(values, [100, 200, 300, 404]);
+    for (variant, value) in Tags::values_map() {
+        assert_eq!(variant.value(), *value);
+    }
+}
+
+#[derive(Const)]
+#[armtype(str)]
+enum Unsized {
+    #[value = "a"]
+    A,
+    #[value = "b"]
+    B,
+}
+
+#[derive(Const)]
+#[armtype([u8])]
+enum UnsizedBytes {
+    #[value = b"a"]
+    A,
+}
+
+#[test]
+fn unsized_armtype() {
+    let value: &'static str = Unsized::B.value();
+    assert_eq!(value, "b");
+    assert!(matches!(Unsized::try_from("a"), Ok(Unsized::A)));
+    let value: &'static [u8] = UnsizedBytes::A.value();
+    assert_eq!(value, b"a");
+    assert!(matches!(UnsizedBytes::from_bytes(b"a"), Ok(UnsizedBytes::A)));
+}
+
+#[derive(Const)]
+#[armtype(&str, value = ident_lower)]
+enum Lowered {
+    Foo,
+    Bar,
+    #[value = "explicit"]
+    Baz,
+}
+
+#[derive(Const)]
+#[armtype(&str, value = ident_snake)]
+enum Snaked {
+    FooBar,
+    Baz,
+}
+
+#[derive(Const)]
+#[armtype(&str, value = ident)]
+enum Named {
+    FooBar,
+}
+
+#[test]
+fn ident_value() {
+    assert_eq!(Lowered::Foo.value(), "foo");
+    assert_eq!(Lowered::Bar.value(), "bar");
+    assert_eq!(Lowered::Baz.value(), "explicit");
+    assert!(matches!(Lowered::try_from("bar"), Ok(Lowered::Bar)));
+    assert_eq!(Snaked::FooBar.value(), "foo_bar");
+    assert_eq!(Snaked::Baz.value(), "baz");
+    assert_eq!(Named::FooBar.value(), "FooBar");
+}
+
+#[cfg(feature = "ffi")]
+#[derive(Const)]
+#[armtype(u32)]
+#[repr(C)]
+enum FfiCode {
+    #[value = 0xdead]
+    Dead,
+    #[value = 0xbeef]
+    Beef,
+}
+
+#[cfg(feature = "ffi")]
+#[test]
+fn to_ffi() {
+    extern "C" fn code(value: u32) -> u32 { value }
+    assert_eq!(FfiCode::Dead.to_ffi(), 0xdead);
+    assert_eq!(code(FfiCode::Beef.to_ffi()), 0xbeef);
+    let value: &'static u32 = FfiCode::Beef.value();
+    assert_eq!(*value, 0xbeef);
+}
+
+#[test]
+fn predicates() {
+    assert!(Tags::Key.is_key());
+    assert!(!Tags::Key.is_length());
+    assert!(Filled::Named { a: 1, b: 2 }.is_named());
+    assert!(Filled::Unnamed(0, String::new()).is_unnamed());
+    assert!(!Filled::Unit.is_partial());
+    assert!(StatusCode::NotFound.is_not_found());
+    assert!(Protocol::Other(7).is_other());
+    assert!(!Protocol::Other(7).is_ping());
+    let codes = [StatusCode::Success, StatusCode::NotFound, StatusCode::Success];
+    assert_eq!(codes.iter().filter(|code| code.is_success()).count(), 2);
+}
+
+#[derive(Const)]
+#[armtype(i8)]
+enum NegativeHex {
+    #[value(-0x7f)]
+    A,
+    #[value(-0x80)]
+    Min,
+    #[value = 0x7f]
+    Max,
+}
+
+#[test]
+fn negative_hex() {
+    assert_eq!(NegativeHex::A.value(), &-127);
+    assert_eq!(NegativeHex::Min.value(), &i8::MIN);
+    assert!(matches!(NegativeHex::try_from(-0x7fi8), Ok(NegativeHex::A)));
+    assert!(matches!(NegativeHex::try_from(i8::MIN), Ok(NegativeHex::Min)));
+    assert!(matches!(NegativeHex::try_from(0x7fi8), Ok(NegativeHex::Max)));
+    assert!(NegativeHex::try_from(0i8).is_err());
+}
+
+#[test]
+fn try_from_vec() {
+    assert!(matches!(Tags::try_from(vec![0xba, 0x5e]), Ok(Tags::Length)));
+    let bytes = b"data".to_vec();
+    assert!(matches!(Tags::try_from(&bytes), Ok(Tags::Data)));
+    assert!(Tags::try_from(Vec::new()).is_err());
+}
+
+#[cfg(feature = "display")]
+#[derive(Const)]
+#[armtype(u16, display = name)]
+enum DisplayName {
+    #[value = 200]
+    Ok,
+    #[value = 404]
+    NotFound,
+}
+
+#[cfg(feature = "display")]
+#[derive(Const)]
+#[armtype(u16, display = value)]
+enum DisplayValue {
+    #[value = 200]
+    Ok,
+    #[value = 404]
+    NotFound,
+}
+
+#[cfg(feature = "display")]
+#[test]
+fn display() {
+    assert_eq!(DisplayName::Ok.to_string(), "Ok");
+    assert_eq!(DisplayName::NotFound.to_string(), "NotFound");
+    assert_eq!(format!("{:>5}", DisplayName::Ok), "   Ok");
+    assert_eq!(DisplayValue::Ok.to_string(), "200");
+    assert_eq!(DisplayValue::NotFound.to_string(), "404");
+    assert_eq!(format!("{:05}", DisplayValue::Ok), "00200");
+}
+
+#[derive(Const)]
+#[armtype(&[u8])]
+enum ListBytes {
+    #[value(1, 2, 3)]
+    Items,
+    #[value = b"\x01\x02\x04"]
+    Bytes,
+    #[value(0xff, b'a')]
+    Mixed,
+}
+
+#[derive(Const)]
+#[armtype(&[i16])]
+enum ListSigned {
+    #[value(1, -2)]
+    A,
+    #[value(&[3])]
+    B,
+}
+
+#[test]
+fn list_slice_value() {
+    assert_eq!(ListBytes::Items.value(), b"\x01\x02\x03");
+    assert_eq!(ListBytes::Mixed.value(), b"\xffa");
+    assert!(matches!(ListBytes::from_bytes(b"\x01\x02\x03"), Ok(ListBytes::Items)));
+    assert!(matches!(ListBytes::from_bytes(&[1, 2, 4]), Ok(ListBytes::Bytes)));
+    assert!(ListBytes::from_bytes(&[1, 2]).is_err());
+    assert_eq!(ListSigned::A.value(), &[1, -2]);
+    assert_eq!(ListSigned::B.value(), &[3]);
+    assert!(matches!(ListSigned::try_from(&[1i16, -2] as &[i16]), Ok(ListSigned::A)));
+}
+
+#[test]
+fn from_value_or_default() {
+    assert!(matches!(Config::from_value_or_default(2), Config::On));
+    assert!(matches!(Config::from_value_or_default(0), Config::Off));
+    assert!(matches!(Config::from_value_or_default(42), Config::Auto));
+}
+
+#[derive(Const)]
+#[armtype(u8)]
+enum Inner {
+    #[value = 0x10]
+    Ping,
+    #[value = 0x20]
+    Pong,
+}
+
+#[derive(Const)]
+#[armtype(u8)]
+enum Outer {
+    #[value(*Inner::Ping.value())]
+    Ping,
+    #[value(*Inner::Pong.value() + 1)]
+    Pong,
+    #[value = 0x30]
+    Other,
+}
+
+#[derive(Const)]
+#[armtype(&str)]
+enum InnerName {
+    #[value = "ping"]
+    Ping,
+}
+
+#[derive(Const)]
+#[armtype(&str)]
+enum OuterName {
+    #[value(InnerName::Ping.value())]
+    Ping,
+}
+
+const PING: &u8 = Inner::Ping.value();
+
+#[test]
+fn nested_enum_values() {
+    assert_eq!(PING, &0x10);
+    assert_eq!(Outer::Ping.value(), &0x10);
+    assert_eq!(Outer::Pong.value(), &0x21);
+    assert!(matches!(Outer::try_from(0x10u8), Ok(Outer::Ping)));
+    assert!(matches!(Outer::try_from(0x21u8), Ok(Outer::Pong)));
+    assert!(matches!(Outer::try_from(0x30u8), Ok(Outer::Other)));
+    assert!(Outer::try_from(0x20u8).is_err());
+    assert_eq!(OuterName::Ping.value(), "ping");
+    assert!(matches!(OuterName::try_from("ping"), Ok(OuterName::Ping)));
+}
+
+#[cfg(feature = "fromstr")]
+#[derive(Const)]
+#[armtype(u8, from_str = name)]
+#[thisenum(rename_all = "kebab-case")]
+enum KebabName {
+    #[value = 1]
+    FooBar,
+    #[value = 2]
+    Baz,
+    #[value = 3]
+    HTTPCode,
+}
+
+#[cfg(feature = "fromstr")]
+#[derive(Const)]
+#[armtype(u8, from_str = name)]
+#[thisenum(rename_all = "SCREAMING_SNAKE_CASE")]
+enum ScreamingName {
+    #[value = 1]
+    FooBar,
+}
+
+#[cfg(feature = "fromstr")]
+#[test]
+fn from_str_name() {
+    assert!(matches!("foo-bar".parse::<KebabName>(), Ok(KebabName::FooBar)));
+    assert!(matches!("baz".parse::<KebabName>(), Ok(KebabName::Baz)));
+    assert!(matches!("http-code".parse::<KebabName>(), Ok(KebabName::HTTPCode)));
+    assert!("FooBar".parse::<KebabName>().is_err());
+    assert!(matches!("FOO_BAR".parse::<ScreamingName>(), Ok(ScreamingName::FooBar)));
+}
+
+#[cfg(feature = "display")]
+#[derive(Const)]
+#[armtype(u8, display = name)]
+#[thisenum(rename_all = "snake_case")]
+enum SnakeDisplay {
+    #[value = 1]
+    FooBar,
+}
+
+#[cfg(feature = "display")]
+#[test]
+fn display_rename_all() {
+    assert_eq!(SnakeDisplay::FooBar.to_string(), "foo_bar");
+}
+
+#[cfg(all(feature = "display", feature = "fromstr"))]
+#[derive(Const)]
+#[armtype(u8, display = name, from_str = name)]
+#[thisenum(rename_all = "snake_case")]
+enum Dimension {
+    #[value = 1]
+    #[rename = "Width"]
+    Wide,
+    #[value = 2]
+    FullHeight,
+}
+
+#[cfg(all(feature = "display", feature = "fromstr"))]
+#[test]
+fn variant_rename() {
+    assert_eq!(Dimension::Wide.to_string(), "Width");
+    assert_eq!(Dimension::FullHeight.to_string(), "full_height");
+    assert!(matches!("Width".parse::<Dimension>(), Ok(Dimension::Wide)));
+    assert!(matches!("full_height".parse::<Dimension>(), Ok(Dimension::FullHeight)));
+    assert!("wide".parse::<Dimension>().is_err());
+}
+
+#[allow(dead_code)]
+#[derive(Const)]
+#[armtype(u8)]
+#[thisenum(verbose_errors)]
+enum Verbose {
+    #[value = 1]
+    A,
+    #[value = 2]
+    B,
+    #[value = 2]
+    C,
+    #[skip]
+    #[value = 3]
+    D,
+    #[value = 5]
+    E(u8),
+    #[value = 6]
+    #[fill(Default)]
+    F(u8),
+    #[value(7..=9)]
+    G,
+}
+
+#[test]
+fn verbose_errors() {
+    match Verbose::try_from(4u8) {
+        Err(thisenum::Error::InvalidValueWithCandidates(value, name, candidates)) => {
+            assert_eq!(value, "4");
+            assert_eq!(name, "Verbose");
+            assert_eq!(candidates, vec!["1", "6", "7 ..= 9"]);
+        },
+        other => panic!("expected `InvalidValueWithCandidates`, found {:?}", other),
+    }
+    let e = Verbose::try_from(4u8).unwrap_err();
+    assert_eq!(e.to_string(), "Unable to convert `4` to `Verbose`, expected one of: 1, 6, 7 ..= 9");
+    for candidate in [1u8, 6, 8] {
+        assert!(Verbose::try_from(candidate).is_ok());
+    }
+    assert!(matches!(Verbose::try_from(4u8 + 1), Err(thisenum::Error::UnableToReturnVariant(..))));
+}
+
+#[derive(Const)]
+#[armtype(&str)]
+enum Single {
+    #[value = "only"]
+    Only,
+}
+
+#[test]
+fn single_variant() {
+    assert_eq!(Single::Only.value(), "only");
+    assert!(matches!(Single::try_from("only"), Ok(Single::Only)));
+    assert!(Single::try_from("other").is_err());
+    #[cfg(feature = "eq")]
+    assert_eq!(Single::Only, "only");
+}
+
+#[derive(Const)]
+#[armtype(u8)]
+enum Empty {}
+
+#[derive(Const)]
+#[armtype(&[u8])]
+enum EmptyBytes {}
+
+#[test]
+fn empty_enum() {
+    assert!(Empty::try_from(0u8).is_err());
+    assert!(EmptyBytes::try_from(b"\x00" as &[u8]).is_err());
+    assert!(Empty::ALL.is_empty());
+}
+
+#[derive(thisenum::ConstEach)]
+enum EmptyEach {}
+
+#[test]
+fn empty_each_enum() {
+    assert!(EmptyEach::variants().is_empty());
+    assert!(EmptyEach::values::<u8>().is_empty());
+    assert!(EmptyEach::from_value(0u8).is_none());
+}
+
+#[derive(Const)]
+#[armtype(std::time::Duration)]
+enum Timeout {
+    #[value(std::time::Duration::from_secs(5))]
+    Short,
+    #[value(std::time::Duration::from_millis(60_000))]
+    Long,
+}
+
+const SHORT_TIMEOUT: &std::time::Duration = Timeout::Short.value();
+
+#[test]
+fn duration() {
+    use std::time::Duration;
+    assert_eq!(SHORT_TIMEOUT, &Duration::from_secs(5));
+    assert_eq!(Timeout::Long.value(), &Duration::from_secs(60));
+    assert!(matches!(Timeout::try_from(Duration::from_secs(60)), Ok(Timeout::Long)));
+    assert!(Timeout::try_from(Duration::from_secs(1)).is_err());
+    let value: Duration = Timeout::Short.into();
+    assert_eq!(value, Duration::from_secs(5));
+    #[cfg(feature = "eq")]
+    assert_eq!(Timeout::Long, Duration::from_secs(60));
+}
+
+#[derive(Const)]
+#[armtype(u8)]
+#[thisenum(derive_try_from = false)]
+enum HandWritten {
+    #[value = 1]
+    One,
+    #[value = 2]
+    Two,
+}
+
+impl TryFrom<u8> for HandWritten {
+    type Error = &'static str;
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        HandWritten::which(&value).ok_or("not a HandWritten")
+    }
+}
+
+#[test]
+fn derive_try_from_false() {
+    assert_eq!(HandWritten::Two.value(), &2);
+    assert!(matches!(HandWritten::try_from(1u8), Ok(HandWritten::One)));
+    assert_eq!(HandWritten::try_from(3u8).err(), Some("not a HandWritten"));
+    assert_eq!(format!("{:?}", HandWritten::One), "HandWritten::One: 1");
+    let value: u8 = HandWritten::Two.into();
+    assert_eq!(value, 2);
+    #[cfg(feature = "eq")]
+    assert_eq!(HandWritten::One, 1);
+}
+
+const fn unique_tag(index: u8) -> u8 {
+    index * 2
+}
+
+#[derive(Const)]
+#[armtype(u8)]
+#[thisenum(unique)]
+enum UniqueInts {
+    #[value(unique_tag(1))]
+    A,
+    #[value = 3]
+    B,
+    #[value(4..=5)]
+    C,
+    #[skip]
+    #[value = 3]
+    D,
+}
+
+#[derive(Const)]
+#[armtype(&str)]
+#[thisenum(unique)]
+enum UniqueStrs {
+    #[value = "a"]
+    A,
+    #[value = "ab"]
+    B,
+}
+
+#[test]
+fn unique() {
+    assert_eq!(UniqueInts::A.value(), &2);
+    assert!(matches!(UniqueInts::try_from(3u8), Ok(UniqueInts::B)));
+    assert!(matches!(UniqueInts::try_from(5u8), Ok(UniqueInts::C)));
+    assert_eq!(UniqueInts::D.value(), &3);
+    assert!(matches!(UniqueStrs::try_from("ab"), Ok(UniqueStrs::B)));
+}
+
+#[allow(dead_code)]
+#[derive(Const)]
+#[armtype(&[u8])]
+enum ExifTag {
+    #[armtype([u8; 2])]
+    #[value = b"\x00\x01"]
+    ImageWidth,
+    #[value = b"\x00\x02"]
+    ImageLength,
+}
+
+#[cfg(feature = "eq")]
+#[test]
+fn arm_eq() {
+    assert_eq!(ExifTag::ImageWidth, [0x00, 0x01]);
+    assert_eq!([0x00, 0x01], ExifTag::ImageWidth);
+    assert_ne!(ExifTag::ImageWidth, [0x00, 0x02]);
+    assert_ne!(ExifTag::ImageLength, [0x00, 0x02]);
+    assert_eq!(ExifTag::ImageLength, b"\x00\x02" as &[u8]);
+}
+
+#[test]
+fn try_from_prefix() {
+    assert!(matches!(ExifTag::try_from_prefix(b"\x00\x01\x03\x00"), Ok((ExifTag::ImageWidth, 2))));
+    assert!(matches!(ExifTag::try_from_prefix(b"\x00\x02"), Ok((ExifTag::ImageLength, 2))));
+    assert!(matches!(ExifTag::try_from_prefix(b"\x00"), Err(thisenum::Error::InvalidValue(..))));
+    assert!(matches!(Tags::try_from_prefix(b"\x00\x01\x7f\xba\x5e"), Ok((Tags::Key, 3))));
+    assert!(Tags::try_from_prefix(b"").is_err());
+}
+
+#[derive(Const)]
+#[armtype(std::net::Ipv4Addr)]
+enum Address {
+    #[value(std::net::Ipv4Addr::new(127, 0, 0, 1))]
+    Localhost,
+    #[value(std::net::Ipv4Addr::BROADCAST)]
+    Broadcast,
+}
+
+const LOCALHOST: &std::net::Ipv4Addr = Address::Localhost.value();
+
+#[test]
+fn ipv4_addr() {
+    use std::net::Ipv4Addr;
+    assert_eq!(LOCALHOST, &Ipv4Addr::LOCALHOST);
+    assert_eq!(Address::Broadcast.value(), &Ipv4Addr::new(255, 255, 255, 255));
+    assert!(matches!(Address::try_from(Ipv4Addr::new(127, 0, 0, 1)), Ok(Address::Localhost)));
+    assert!(matches!(Address::try_from(Ipv4Addr::BROADCAST), Ok(Address::Broadcast)));
+    assert!(Address::try_from(Ipv4Addr::UNSPECIFIED).is_err());
+    #[cfg(feature = "eq")]
+    assert_eq!(Address::Localhost, Ipv4Addr::LOCALHOST);
+}
+
+#[derive(Const)]
+#[armtype(u32, deref)]
+enum Offset {
+    #[value = 0x10]
+    A,
+}
+
+#[derive(Const)]
+#[armtype(&[u8], deref)]
+enum DerefTags {
+    #[value = b"\xba\x5e"]
+    Length,
+}
+
+#[derive(Const)]
+#[armtype(&str, deref)]
+enum DerefStr {
+    #[value = "foo"]
+    Foo,
+}
+
+#[test]
+fn deref() {
+    assert_eq!(*Offset::A + 1, 0x11);
+    assert_eq!(DerefTags::Length.len(), 2);
+    assert_eq!(&*DerefStr::Foo, "foo");
+}
+
+#[derive(Const)]
+#[armtype(&str)]
+#[thisenum(unique)]
+enum ProtocolName {
+    #[value = concat!("prefix_", "a")]
+    A,
+    #[value(concat!("prefix_", "b"))]
+    B,
+    #[value(stringify!(prefix_c))]
+    C,
+}
+
+#[test]
+fn concat_value() {
+    assert_eq!(ProtocolName::A.value(), "prefix_a");
+    assert_eq!(ProtocolName::B.value(), "prefix_b");
+    assert_eq!(ProtocolName::C.value(), "prefix_c");
+    assert!(matches!(ProtocolName::try_from("prefix_a"), Ok(ProtocolName::A)));
+    assert!(matches!(ProtocolName::try_from("prefix_c"), Ok(ProtocolName::C)));
+    assert!(ProtocolName::try_from("prefix_").is_err());
+    #[cfg(feature = "eq")]
+    assert_eq!(ProtocolName::A, "prefix_a");
+}
+
+#[cfg(feature = "fromstr")]
+#[derive(Const)]
+#[armtype(&str, from_str = value)]
+#[value_prefix = "prefix_"]
+enum ParsedStr {
+    #[value = "a"]
+    A,
+    #[value = "b"]
+    B,
+}
+
+#[cfg(feature = "fromstr")]
+#[derive(Const)]
+#[armtype(&[u8], from_str = value)]
+enum ParsedBytes {
+    #[value = b"\x00\x01\x7f"]
+    Key,
+}
+
+#[derive(Const)]
+#[armtype(&str)]
+enum OwnFromStr {
+    #[value = "a"]
+    A,
+}
+
+impl std::str::FromStr for OwnFromStr {
+    type Err = ();
+    fn from_str(_: &str) -> Result<Self, Self::Err> {
+        Ok(OwnFromStr::A)
+    }
+}
+
+#[cfg(feature = "fromstr")]
+#[test]
+fn from_str_value() {
+    assert!(matches!("prefix_b".parse::<ParsedStr>(), Ok(ParsedStr::B)));
+    assert!("b".parse::<ParsedStr>().is_err());
+    assert!(matches!("\u{0}\u{1}\u{7f}".parse::<ParsedBytes>(), Ok(ParsedBytes::Key)));
+}
+
+#[test]
+fn own_from_str() {
+    assert!(matches!("anything".parse::<OwnFromStr>(), Ok(OwnFromStr::A)));
+    assert_eq!(OwnFromStr::A.value(), "a");
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[repr(transparent)]
+pub struct BStr([u8]);
+
+impl BStr {
+    pub const fn new(bytes: &[u8]) -> &BStr {
+        // SAFETY: `BStr` is a `#[repr(transparent)]` wrapper around `[u8]`
+        unsafe { &*(bytes as *const [u8] as *const BStr) }
+    }
+}
+
+#[derive(Const)]
+#[armtype(&BStr)]
+enum Magic {
+    #[value(BStr::new(b"\x7fELF"))]
+    Elf,
+    #[value(BStr::new(b"MZ"))]
+    Exe,
+}
+
+const ELF: &BStr = Magic::Elf.value();
+
+#[test]
+fn newtype_slice() {
+    assert_eq!(ELF, BStr::new(b"\x7fELF"));
+    assert_eq!(&Magic::Exe.value().0, b"MZ");
+    assert!(matches!(Magic::try_from(BStr::new(b"MZ")), Ok(Magic::Exe)));
+    assert!(Magic::try_from(BStr::new(b"M")).is_err());
+    #[cfg(feature = "eq")]
+    assert_eq!(Magic::Elf, BStr::new(b"\x7fELF"));
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct FieldName(&'static str);
+
+impl std::ops::Deref for FieldName {
+    type Target = str;
+    fn deref(&self) -> &str {
+        self.0
+    }
+}
+
+#[derive(Const)]
+#[armtype(&FieldName, return = str)]
+enum Field {
+    #[value(&FieldName("width"))]
+    Width,
+    #[value(&FieldName("height"))]
+    Height,
+}
+
+#[derive(Const)]
+#[armtype(&[u8; 2], return = [u8])]
+enum Pair {
+    #[value = b"ab"]
+    A,
+}
+
+#[test]
+fn return_type() {
+    let width: &'static str = Field::Width.value();
+    assert_eq!(width, "width");
+    assert_eq!(Field::Height.value().len(), 6);
+    assert!(matches!(Field::try_from(&FieldName("height")), Ok(Field::Height)));
+    assert_eq!(Field::Height.into_value(), &FieldName("height"));
+    let pair: &[u8] = Pair::A.value();
+    assert_eq!(pair, b"ab");
+}
+
+#[derive(Const)]
+#[armtype(usize)]
+enum Table<const N: usize> {
+    #[value(N)]
+    Size,
+    #[value = 0]
+    Empty,
+}
+
+#[test]
+fn const_generic() {
+    assert_eq!(Table::<4>::Size.value(), &4);
+    assert_eq!(Table::<4>::Empty.value(), &0);
+    assert!(matches!(Table::<8>::try_from(8usize), Ok(Table::Size)));
+    assert!(matches!(Table::<8>::try_from(0usize), Ok(Table::Empty)));
+    assert!(Table::<8>::try_from(4usize).is_err());
+    assert_eq!(Table::<2>::ALL.len(), 2);
+}
+
+trait Register {
+    const ADDRESS: u16;
+}
+
+struct ControlReg;
+impl Register for ControlReg {
+    const ADDRESS: u16 = 0x40;
+}
+
+struct StatusReg;
+impl Register for StatusReg {
+    const ADDRESS: u16 = 0x41;
+}
+
+#[derive(Const)]
+#[armtype(u16)]
+enum RegisterAddress {
+    #[value(<ControlReg as Register>::ADDRESS)]
+    ControlReg,
+    #[value(<StatusReg as Register>::ADDRESS)]
+    StatusReg,
+}
+
+#[test]
+fn trait_assoc_const() {
+    assert_eq!(RegisterAddress::ControlReg.value(), &0x40);
+    assert_eq!(RegisterAddress::StatusReg.value(), &0x41);
+    assert!(matches!(RegisterAddress::try_from(0x40u16), Ok(RegisterAddress::ControlReg)));
+    assert!(matches!(RegisterAddress::try_from(0x41u16), Ok(RegisterAddress::StatusReg)));
+    assert!(RegisterAddress::try_from(0x42u16).is_err());
+}
+
+#[test]
+fn index() {
+    assert_eq!(StatusCode::Informational.to_index(), 0);
+    assert_eq!(StatusCode::NotFound.to_index(), 3);
+    for (index, variant) in StatusCode::ALL.iter().enumerate() {
+        assert_eq!(variant.to_index(), index);
+        assert_eq!(StatusCode::from_index(index).map(|v| v.value()), Some(variant.value()));
+    }
+    assert!(StatusCode::from_index(StatusCode::ALL.len()).is_none());
+    const SUCCESS: usize = StatusCode::Success.to_index();
+    assert!(matches!(StatusCode::from_index(SUCCESS), Some(StatusCode::Success)));
+}
+
+#[derive(Const)]
+#[armtype(char)]
+enum CharClass {
+    #[value('a'..='z')]
+    Lowercase,
+    #[value('A'..='Z')]
+    Uppercase,
+    #[value = '_']
+    Underscore,
+}
+
+#[test]
+fn char_range() {
+    assert!(matches!(CharClass::try_from('m'), Ok(CharClass::Lowercase)));
+    assert!(matches!(CharClass::try_from('a'), Ok(CharClass::Lowercase)));
+    assert!(matches!(CharClass::try_from('z'), Ok(CharClass::Lowercase)));
+    assert!(matches!(CharClass::try_from('Q'), Ok(CharClass::Uppercase)));
+    assert!(matches!(CharClass::try_from('_'), Ok(CharClass::Underscore)));
+    assert!(CharClass::try_from('!').is_err());
+    assert_eq!(CharClass::Lowercase.value(), &'a');
+    assert_eq!(CharClass::Uppercase.value(), &'A');
+}
+
+#[cfg(feature = "eq")]
+#[test]
+fn eq_str() {
+    fn eq_unsized<T: PartialEq<str>>(lhs: &T, rhs: &str) -> bool {
+        lhs == rhs
+    }
+    let owned = String::from("this");
+    let borrowed: &str = &owned;
+    assert_eq!(MyStrEnum::This, *borrowed);
+    assert_eq!(*borrowed, MyStrEnum::This);
+    assert_ne!(MyStrEnum::That, *borrowed);
+    assert!(eq_unsized(&MyStrEnum::This, borrowed));
+    assert!(!eq_unsized(&MyStrEnum::That, &owned));
+}
+
+#[derive(Const)]
+#[armtype(u8)]
+enum IntNameValue {
+    #[value = 0]
+    A,
+    #[value = 1]
+    B,
+}
+
+#[derive(Const)]
+#[armtype(u8)]
+enum IntList {
+    #[value(0)]
+    A,
+    #[value(1)]
+    B,
+}
+
+#[derive(Const)]
+#[armtype(&[u8])]
+enum BytesNameValue {
+    #[value = b"x"]
+    X,
+    #[value = b"yz"]
+    Yz,
+}
+
+#[derive(Const)]
+#[armtype(&[u8])]
+enum BytesList {
+    #[value(b"x")]
+    X,
+    #[value(b"yz")]
+    Yz,
+}
+
+#[derive(Const)]
+#[armtype(&str)]
+enum StrNameValue {
+    #[value = "s"]
+    S,
+    #[value = "t"]
+    T,
+}
+
+#[derive(Const)]
+#[armtype(&str)]
+enum StrList {
+    #[value("s")]
+    S,
+    #[value("t")]
+    T,
+}
+
+#[test]
+fn list_form() {
+    assert_eq!(IntNameValue::A.value(), IntList::A.value());
+    assert_eq!(IntNameValue::B.value(), IntList::B.value());
+    assert!(matches!(IntList::try_from(1u8), Ok(IntList::B)));
+    assert!(IntList::try_from(2u8).is_err());
+    assert_eq!(IntNameValue::try_from(2u8).unwrap_err().to_string().replace("IntNameValue", ""), IntList::try_from(2u8).unwrap_err().to_string().replace("IntList", ""));
+    assert_eq!(BytesNameValue::X.value(), BytesList::X.value());
+    assert_eq!(BytesNameValue::Yz.value(), BytesList::Yz.value());
+    assert!(matches!(BytesList::try_from(b"yz" as &[u8]), Ok(BytesList::Yz)));
+    assert!(BytesList::try_from(b"y" as &[u8]).is_err());
+    assert_eq!(StrNameValue::S.value(), StrList::S.value());
+    assert_eq!(StrNameValue::T.value(), StrList::T.value());
+    assert!(matches!(StrList::try_from("t"), Ok(StrList::T)));
+    assert!(StrList::try_from("u").is_err());
+    #[cfg(feature = "eq")]
+    {
+        assert_eq!(IntList::A, 0);
+        assert_eq!(1, IntList::B);
+        assert_eq!(BytesList::X, &b"x"[..]);
+        assert_eq!(StrList::S, "s");
+        assert_eq!("t", StrList::T);
+        assert_ne!(StrList::S, "t");
+    }
+}
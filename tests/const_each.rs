@@ -0,0 +1,318 @@
+use thisenum::{
+    Const,
+    ConstEach,
+};
+
+#[derive(ConstEach)]
+enum Wide {
+    #[armtype(u128)]
+    #[value = 0x1_0000_0000_0000_0000]
+    Above,
+    #[value(0x1_0000_0000_0000_0000_i128)]
+    Inferred,
+}
+
+#[test]
+fn wide_integers() {
+    assert_eq!(Wide::Above.value::<u128>(), Some(&(u64::MAX as u128 + 1)));
+    assert!(Wide::Above.value::<u64>().is_none());
+    assert_eq!(Wide::Inferred.value::<i128>(), Some(&(u64::MAX as i128 + 1)));
+    assert!(Wide::Inferred.value::<u128>().is_none());
+}
+
+#[derive(Const, ConstEach)]
+#[armtype(u8)]
+#[thisenum(value_as)]
+enum Both {
+    #[armtype(u8)]
+    #[value = 1]
+    A,
+    #[value = 2]
+    B,
+}
+
+#[test]
+fn derive_both() {
+    assert_eq!(Both::A.value(), &1);
+    assert_eq!(Both::A.value_as::<u8>(), Some(&1));
+    assert_eq!(Both::B.value(), &2);
+    assert_eq!(Both::B.value_as::<u8>(), Some(&2));
+    assert!(Both::B.value_as::<i32>().is_none());
+    assert!(matches!(Both::try_from(2u8), Ok(Both::B)));
+    assert!(matches!(Both::from_value_as(2u8), Some(Both::B)));
+    assert!(matches!(Both::from_value(2u8), Some(Both::B)));
+    assert_eq!(Wide::Above.value_as::<u128>(), Wide::Above.value::<u128>());
+}
+
+#[derive(ConstEach)]
+enum GenericEach<'a, T> {
+    #[armtype(u8)]
+    #[value = 1]
+    A,
+    #[value = "b"]
+    B,
+    #[allow(dead_code)]
+    _Phantom(std::marker::PhantomData<&'a T>),
+}
+
+#[test]
+fn generic() {
+    assert_eq!(GenericEach::<String>::A.value::<u8>(), Some(&1));
+    assert_eq!(GenericEach::<String>::B.value::<&str>(), Some(&"b"));
+}
+
+#[derive(ConstEach)]
+enum CustomEnum {
+    #[armtype(&[u8])]
+    #[value = b"\x01\x00"]
+    A,
+    #[value = "foo"]
+    B,
+    #[armtype(f32)]
+    #[value = 3.5]
+    C,
+    D,
+    #[allow(dead_code)]
+    E(u8),
+}
+
+#[test]
+fn type_name() {
+    assert!(CustomEnum::A.type_name().contains("[u8]"));
+    assert!(CustomEnum::B.type_name().contains("str"));
+    assert!(CustomEnum::C.type_name().contains("f32"));
+    assert_eq!(CustomEnum::D.type_name(), "()");
+    assert!(CustomEnum::C.value_as::<f64>().is_none());
+}
+
+#[test]
+fn value_any() {
+    assert!(CustomEnum::C.value_any().is::<f32>());
+    assert!(!CustomEnum::C.value_any().is::<f64>());
+    assert_eq!(CustomEnum::B.value_any().downcast_ref::<&str>(), Some(&"foo"));
+    assert!(CustomEnum::D.value_any().is::<()>());
+}
+
+#[derive(ConstEach)]
+enum LongFormEach {
+    #[const_type(u16)]
+    #[const_value = 7]
+    A,
+    #[const_value = "b"]
+    B,
+}
+
+#[test]
+fn long_form_attributes() {
+    assert_eq!(LongFormEach::A.value::<u16>(), Some(&7));
+    assert_eq!(LongFormEach::B.value::<&str>(), Some(&"b"));
+}
+
+#[cfg(not(feature = "no_std"))]
+fn expensive() -> Vec<u8> {
+    vec![1, 2, 3]
+}
+
+#[cfg(not(feature = "no_std"))]
+#[derive(ConstEach)]
+enum Computed {
+    #[armtype(String)]
+    #[value(String::from("computed"))]
+    Name,
+    #[armtype(Vec<u8>)]
+    #[value(expensive())]
+    Data,
+    #[value(1u8)]
+    Literal,
+    #[armtype(Vec<u16>)]
+    #[value(vec![4, 5])]
+    Owned,
+    #[armtype(String)]
+    #[value(format!("{}-{}", 1, 2))]
+    Formatted,
+}
+
+#[cfg(not(feature = "no_std"))]
+#[test]
+fn computed_cached() {
+    let first = Computed::Name.value::<String>().unwrap();
+    let second = Computed::Name.value::<String>().unwrap();
+    assert_eq!(first, "computed");
+    assert!(std::ptr::eq(first, second));
+    assert_eq!(Computed::Data.value::<Vec<u8>>(), Some(&vec![1, 2, 3]));
+    assert!(std::ptr::eq(Computed::Data.value_any(), Computed::Data.value_any()));
+    assert!(Computed::Data.type_name().contains("Vec<u8>"));
+    assert_eq!(Computed::Literal.value::<u8>(), Some(&1));
+    assert_eq!(Computed::Owned.value::<Vec<u16>>(), Some(&vec![4, 5]));
+    assert!(std::ptr::eq(Computed::Owned.value_any(), Computed::Owned.value_any()));
+    assert_eq!(Computed::Formatted.value::<String>().map(String::as_str), Some("1-2"));
+}
+
+#[derive(ConstEach)]
+enum Floats {
+    #[value(2.5f32)]
+    Single,
+    #[value = 2.5]
+    Double,
+}
+
+#[test]
+fn suffixed_float() {
+    assert_eq!(Floats::Single.value::<f32>(), Some(&2.5f32));
+    assert!(Floats::Single.value::<f64>().is_none());
+    assert_eq!(Floats::Double.value::<f64>(), Some(&2.5f64));
+    assert!(Floats::Double.value::<f32>().is_none());
+    assert_eq!(Floats::Single.type_name(), "f32");
+    assert_eq!(Floats::Double.type_name(), "f64");
+}
+
+#[test]
+fn variants() {
+    let names = CustomEnum::variants().iter().map(|v| v.type_name()).collect::<Vec<_>>();
+    assert_eq!(names.len(), 4);
+    assert_eq!(names[2], "f32");
+    assert!(matches!(CustomEnum::variants().last(), Some(CustomEnum::D)));
+    assert_eq!(CustomEnum::variants().iter().filter_map(|v| v.value::<f32>()).collect::<Vec<_>>(), [&3.5]);
+    assert_eq!(GenericEach::<String>::variants().len(), 2);
+}
+
+#[derive(ConstEach)]
+enum OrderedEach {
+    #[value = 1]
+    /// Docs between the value and the armtype
+    #[armtype(u16)]
+    TypeLast,
+    #[armtype(u16)]
+    /// Docs between the armtype and the value
+    #[value = 2]
+    TypeFirst,
+}
+
+#[test]
+fn attribute_order() {
+    assert_eq!(OrderedEach::TypeLast.value::<u16>(), Some(&1));
+    assert_eq!(OrderedEach::TypeFirst.value::<u16>(), Some(&2));
+}
+
+#[test]
+fn value_unwrap() {
+    assert_eq!(CustomEnum::C.value_unwrap::<f32>(), &3.5);
+    assert_eq!(CustomEnum::B.value_unwrap::<&str>(), &"foo");
+}
+
+#[test]
+#[should_panic(expected = "`CustomEnum::C` has no value of type `f64`, found `f32`")]
+fn value_unwrap_wrong_type() {
+    let _ = CustomEnum::C.value_unwrap::<f64>();
+}
+
+#[derive(ConstEach)]
+enum UnsizedEach {
+    #[armtype(str)]
+    #[value = "a"]
+    A,
+}
+
+#[test]
+fn unsized_armtype() {
+    assert_eq!(UnsizedEach::A.value::<&str>(), Some(&"a"));
+}
+
+#[derive(ConstEach)]
+#[armtype(u16)]
+enum Header {
+    #[value = 1]
+    Key,
+    #[value = 24250]
+    Length,
+    #[armtype(&str)]
+    #[value = "name"]
+    Name,
+    #[armtype(u8)]
+    #[value = 1]
+    Flag,
+}
+
+#[test]
+fn typed_from_value() {
+    assert_eq!(Header::Length.value::<u16>(), Some(&24250));
+    assert!(matches!(Header::from_value(24250u16), Some(Header::Length)));
+    assert!(matches!(Header::from_value(1u16), Some(Header::Key)));
+    assert!(matches!(Header::from_value(1u8), Some(Header::Flag)));
+    assert!(matches!(Header::from_value("name"), Some(Header::Name)));
+    assert!(Header::from_value(24250u32).is_none());
+    assert!(Header::from_value_as(2u16).is_none());
+}
+
+#[test]
+fn value_copy() {
+    assert_eq!(Header::Length.value_copy::<u16>(), Some(24250));
+    assert_eq!(Header::Flag.value_copy::<u8>(), Some(1));
+    assert_eq!(Header::Name.value_copy::<&str>(), Some("name"));
+    assert!(Header::Length.value_copy::<u8>().is_none());
+    assert_eq!(Both::B.value_copy::<u8>(), Some(2));
+}
+
+#[test]
+fn values() {
+    assert_eq!(CustomEnum::values::<&str>(), [&"foo"]);
+    assert_eq!(CustomEnum::values::<f32>(), [&3.5]);
+    assert!(CustomEnum::values::<u64>().is_empty());
+    assert_eq!(Header::values::<u16>(), [&1, &24250]);
+}
+
+#[test]
+fn map() {
+    assert_eq!(Floats::map(|value: &f32| value * 2.0), [5.0]);
+    assert_eq!(Casts::map(|value: &f32| value * 2.0), [2.0]);
+    assert_eq!(Header::map(|value: &u16| u32::from(*value) * 2), [2, 48500]);
+    assert!(Header::map(|value: &u64| *value).is_empty());
+}
+
+#[derive(ConstEach)]
+enum Casts {
+    #[armtype(f32)]
+    #[value = 1]
+    IntToFloat,
+    #[armtype(u32)]
+    #[value = 'a']
+    CharToInt,
+    #[armtype(u8)]
+    #[value = true]
+    BoolToInt,
+}
+
+#[test]
+fn castable_literals() {
+    assert_eq!(Casts::IntToFloat.value::<f32>(), Some(&1.0));
+    assert_eq!(Casts::CharToInt.value::<u32>(), Some(&97));
+    assert_eq!(Casts::BoolToInt.value::<u8>(), Some(&1));
+}
+
+#[derive(ConstEach)]
+enum Signs {
+    #[value(-5)]
+    Negative,
+    #[value(-5i8)]
+    NegativeI8,
+    #[value(-2.5)]
+    NegativeFloat,
+    #[value = true]
+    Enabled,
+    #[value(false)]
+    Disabled,
+}
+
+#[test]
+fn negative_and_bool_literals() {
+    assert_eq!(Signs::Negative.value::<i32>(), Some(&-5));
+    assert_eq!(Signs::Negative.value::<i8>(), None);
+    assert_eq!(Signs::NegativeI8.value::<i8>(), Some(&-5));
+    assert_eq!(Signs::NegativeFloat.value::<f64>(), Some(&-2.5));
+    assert_eq!(Signs::Enabled.value::<bool>(), Some(&true));
+    assert_eq!(Signs::Disabled.value::<bool>(), Some(&false));
+    assert_eq!(Signs::Negative.type_name(), "i32");
+    assert_eq!(Signs::NegativeI8.type_name(), "i8");
+    assert!(matches!(Signs::from_value_as(-5i32), Some(Signs::Negative)));
+    assert!(matches!(Signs::from_value_as(false), Some(Signs::Disabled)));
+}
@@ -0,0 +1,59 @@
+#![deny(warnings, clippy::all, clippy::pedantic)]
+
+use thisenum::{
+    Const,
+    ConstEach,
+};
+
+#[derive(Const)]
+#[armtype(f64)]
+enum Gain {
+    #[value = 0.5]
+    Half,
+    #[value = 1.0]
+    Unity,
+}
+
+#[derive(Const)]
+#[armtype(&str, default)]
+enum Method {
+    #[value = "GET"]
+    Get,
+    #[value = "POST"]
+    Post,
+    #[value = "PUT"]
+    #[default]
+    Put,
+}
+
+#[derive(Const)]
+#[armtype(u16)]
+enum Port {
+    #[value(0..=1023)]
+    WellKnown,
+    #[value = 8080]
+    Proxy,
+}
+
+#[derive(ConstEach)]
+enum Setting {
+    #[armtype(bool)]
+    #[value = true]
+    Enabled,
+    #[armtype(&str)]
+    #[value = "name"]
+    Name,
+}
+
+#[test]
+fn deny_lints() {
+    assert!((Gain::Half.value() - 0.5).abs() < f64::EPSILON);
+    assert!(matches!(Gain::try_from(1.0), Ok(Gain::Unity)));
+    assert!(matches!(Method::try_from("POST"), Ok(Method::Post)));
+    assert!(matches!(Method::default(), Method::Put));
+    assert_eq!(Method::Get.value(), "GET");
+    assert!(matches!(Port::try_from(80u16), Ok(Port::WellKnown)));
+    assert_eq!(Port::Proxy.value(), &8080);
+    assert_eq!(Setting::Enabled.value_as::<bool>(), Some(&true));
+    assert_eq!(Setting::Name.value_as::<&str>(), Some(&"name"));
+}
@@ -0,0 +1,48 @@
+#![cfg(feature = "no_std")]
+#![no_std]
+extern crate alloc;
+
+use alloc::string::ToString;
+use thisenum::Const;
+
+#[derive(Const)]
+#[allow(dead_code)]
+#[armtype(&[u8])]
+enum Tags {
+    #[value = b"\x00\x01\x7f"]
+    Key,
+    #[value = b"\xba\x5e"]
+    Length,
+    #[value = b"\xba\x5e"]
+    Duplicate,
+}
+
+#[derive(Const)]
+#[allow(dead_code)]
+#[armtype(u8)]
+enum Bytes {
+    #[value = 0x7f]
+    A,
+    #[value = 0x3b]
+    B(u8),
+}
+
+#[test]
+fn no_std() {
+    assert_eq!(Tags::Key.value(), b"\x00\x01\x7f");
+    assert!(matches!(Tags::try_from(b"\x00\x01\x7f" as &[u8]), Ok(Tags::Key)));
+    assert!(matches!(Tags::try_from(b"\xba\x5e" as &[u8]), Err(thisenum::Error::UnreachableValue(_))));
+    assert!(matches!(Bytes::try_from(0x3bu8), Err(thisenum::Error::UnableToReturnVariant(_))));
+    let value: u8 = Bytes::A.into();
+    assert_eq!(value, 0x7f);
+}
+
+#[test]
+fn no_std_error() {
+    let err = Tags::try_from(b"\xff" as &[u8]).unwrap_err();
+    assert_eq!(err.to_string(), "Unable to convert `[255]` to `Tags`");
+    let err = Tags::try_from(b"\xba\x5e" as &[u8]).unwrap_err();
+    assert_eq!(err.to_string(), "Multiple associated enum arms defined with value `[186, 94]`");
+    let err = Bytes::try_from(0x3bu8).unwrap_err();
+    assert_eq!(err.to_string(), "Unable to return variant `B` from constant, since the variant has nested arguments");
+}
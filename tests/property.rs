@@ -0,0 +1,135 @@
+use thisenum::Const;
+
+// --------------------------------------------------
+// property harness. a minimal generator rather than
+// `proptest`, so that `thisenum` has no dev-dependencies
+// --------------------------------------------------
+/// Number of random values checked per enum
+const CASES: usize = 4096;
+
+/// Helper function to get the seed of a test case, from the FNV-1a hash of its
+/// name, so that each case draws different values and failures are reproducible
+fn seed(name: &str) -> u64 {
+    name.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| (hash ^ u64::from(byte)).wrapping_mul(0x0100_0000_01b3))
+}
+
+/// Helper function to generate pseudo-random values (xorshift64), see [`seed`]
+fn values(seed: u64) -> impl Iterator<Item = u64> {
+    let mut state = seed | 1;
+    core::iter::repeat_with(move || {
+        state ^= state << 13;
+        state ^= state >> 7;
+        state ^= state << 17;
+        state
+    })
+}
+
+/// Asserts, for a unit-only numeric enum, that:
+/// * every variant's `value()` converts back via `TryFrom` to a variant with the same value,
+///   unless several variants share that value, which yields `UnreachableValue`
+/// * random values either convert to a variant holding that value, or yield `InvalidValue`
+macro_rules! roundtrip {
+    ($test:ident, $enum:ident, $ty:ty) => {
+        #[test]
+        fn $test() {
+            let check = |raw: $ty| {
+                let count = $enum::ALL.iter().filter(|v| *v.value() == raw).count();
+                match $enum::try_from(raw) {
+                    Ok(v) => {
+                        assert_eq!(*v.value(), raw);
+                        assert_eq!(count, 1, "`{}` is shared by {} variants", raw, count);
+                    }
+                    Err(thisenum::Error::UnreachableValue(..)) => {
+                        assert!(count > 1, "`{}` is not shared by several variants", raw)
+                    }
+                    Err(thisenum::Error::InvalidValue(..)) => {
+                        assert_eq!(count, 0, "`{}` is a value of `{}`", raw, stringify!($enum))
+                    }
+                    Err(e) => panic!("unexpected error for `{}`: {}", raw, e),
+                }
+            };
+            $enum::ALL.iter().for_each(|v| check(*v.value()));
+            values(seed(stringify!($test))).take(CASES).for_each(|raw| check(raw as $ty));
+        }
+    };
+}
+
+// --------------------------------------------------
+// enums under test
+// --------------------------------------------------
+#[derive(Const)]
+#[armtype(u8)]
+enum Contiguous {
+    #[value = 0]
+    A,
+    #[value = 1]
+    B,
+    #[value = 2]
+    C,
+    #[value = 3]
+    D,
+}
+
+#[derive(Const)]
+#[armtype(u8)]
+enum Sparse {
+    #[value = 0x01]
+    A,
+    #[value = 0x10]
+    B,
+    #[value = 0x7f]
+    C,
+    #[value = 0xff]
+    D,
+}
+
+#[derive(Const)]
+#[armtype(u16)]
+enum Duplicated {
+    #[value = 7]
+    A,
+    #[value = 7]
+    B,
+    #[value = 300]
+    C,
+}
+
+#[derive(Const)]
+#[armtype(i32)]
+enum Signed {
+    #[value(-1)]
+    Negative,
+    #[value = 0]
+    Zero,
+    #[value = 1]
+    Positive,
+    #[value(i32::MIN)]
+    Min,
+    #[value(i32::MAX)]
+    Max,
+}
+
+#[derive(Const)]
+#[armtype(u64)]
+enum Wide {
+    #[value = 0]
+    A,
+    #[value(u64::MAX)]
+    B,
+    #[value = 0xdead_beef]
+    C,
+}
+
+roundtrip!(contiguous, Contiguous, u8);
+roundtrip!(sparse, Sparse, u8);
+roundtrip!(duplicated, Duplicated, u16);
+roundtrip!(signed, Signed, i32);
+roundtrip!(wide, Wide, u64);
+
+#[test]
+fn exhaustive_u8() {
+    for raw in 0..=u8::MAX {
+        assert_eq!(Contiguous::try_from(raw).is_ok(), raw < 4);
+        assert_eq!(Sparse::try_from(raw).is_ok(), matches!(raw, 0x01 | 0x10 | 0x7f | 0xff));
+    }
+}